@@ -203,6 +203,8 @@ use core::pin::{Pin, PinCoerceUnsized};
 use core::ptr::{self, NonNull, Unique};
 use core::task::{Context, Poll};
 
+use safety::{ensures, requires};
+
 #[cfg(not(no_global_oom_handling))]
 use crate::alloc::handle_alloc_error;
 use crate::alloc::{AllocError, Allocator, Global, Layout};
@@ -1057,6 +1059,8 @@ impl<T: ?Sized> Box<T> {
     #[stable(feature = "box_raw", since = "1.4.0")]
     #[inline]
     #[must_use = "call `drop(Box::from_raw(ptr))` if you intend to drop the `Box`"]
+    #[requires(!raw.is_null())]
+    #[ensures(|result| ptr::eq(&**result, raw))]
     pub unsafe fn from_raw(raw: *mut T) -> Self {
         unsafe { Self::from_raw_in(raw, Global) }
     }
@@ -2137,3 +2141,40 @@ impl<E: Error> Error for Box<E> {
 #[cfg(not(bootstrap))]
 #[unstable(feature = "pointer_like_trait", issue = "none")]
 impl<T> PointerLike for Box<T> {}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::cell::Cell;
+    use core::kani;
+    use core::verify_support::DropCounter;
+
+    use crate::boxed::Box;
+
+    #[kani::proof_for_contract(Box::<u32>::from_raw)]
+    pub fn verify_from_raw_into_raw() {
+        let value: u32 = kani::any();
+        let raw = Box::into_raw(Box::new(value));
+        let b = unsafe { Box::from_raw(raw) };
+        assert!(*b == value);
+    }
+
+    #[kani::proof]
+    pub fn verify_from_raw_into_raw_drops_once() {
+        let drops = Cell::new(0);
+        let value: u32 = kani::any();
+        let raw = Box::into_raw(Box::new(DropCounter::new(value, &drops)));
+
+        // Leaking the box into a raw pointer must not run the destructor
+        assert!(drops.get() == 0);
+
+        let b = unsafe { Box::from_raw(raw) };
+        assert!(b.value == value);
+        assert!(drops.get() == 0);
+
+        // Dropping the reconstructed box runs the destructor and frees the
+        // allocation exactly once
+        drop(b);
+        assert!(drops.get() == 1);
+    }
+}
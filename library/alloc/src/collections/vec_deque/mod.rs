@@ -3121,4 +3121,38 @@ mod verify {
             assert!(deque[k] == arr[k]);
         }
     }
+
+    // pub fn push_back(&mut self, value: T)
+    // pub fn pop_front(&mut self) -> Option<T>
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_vecdeque_fifo() {
+        const CAP: usize = 4;
+        let mut deque: VecDeque<u32> = VecDeque::with_capacity(CAP);
+        let cap = deque.capacity();
+        kani::assume(cap == CAP);
+
+        // Rotate `head` to an arbitrary slot so that the pushes below can run
+        // past the end of the buffer and wrap around to its start.
+        let shift = kani::any_where(|&x: &usize| x < CAP);
+        for _ in 0..shift {
+            deque.push_back(0);
+            deque.pop_front();
+        }
+        assert!(deque.is_empty());
+
+        let values: [u32; CAP] = kani::Arbitrary::any_array();
+        let count = kani::any_where(|&x: &usize| x <= CAP);
+        for &v in &values[..count] {
+            deque.push_back(v);
+        }
+        kani::cover!(deque.head + deque.len > cap);
+
+        for &v in &values[..count] {
+            assert_eq!(deque.pop_front(), Some(v));
+        }
+        assert_eq!(deque.pop_front(), None);
+        // Everything fit without reallocating.
+        assert_eq!(deque.capacity(), cap);
+    }
 }
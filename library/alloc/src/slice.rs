@@ -919,3 +919,84 @@ impl<T> sort::stable::BufGuard<T> for Vec<T> {
         self.spare_capacity_mut()
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::kani;
+
+    const PARTS: usize = 3;
+    const PART_LEN: usize = 2;
+
+    /// Returns up to `PARTS` arbitrary slices, each of arbitrary length up to
+    /// `PART_LEN`, backed by `storage`.
+    fn any_parts(storage: &[[u8; PART_LEN]; PARTS]) -> ([&[u8]; PARTS], usize) {
+        let parts = storage.each_ref().map(|part| {
+            let len = kani::any_where(|len: &usize| *len <= PART_LEN);
+            &part[..len]
+        });
+        // Allow an empty outer collection as well.
+        let count = kani::any_where(|count: &usize| *count <= PARTS);
+        (parts, count)
+    }
+
+    /// Checks that `result` consists of the given parts in order, with `sep`
+    /// between each pair of consecutive parts.
+    fn check_interleaved(result: &[u8], parts: &[&[u8]], sep: &[u8]) {
+        let mut offset = 0;
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                assert_eq!(&result[offset..offset + sep.len()], sep);
+                offset += sep.len();
+            }
+            assert_eq!(&result[offset..offset + part.len()], *part);
+            offset += part.len();
+        }
+        assert_eq!(offset, result.len());
+    }
+
+    // fn concat(slice: &Self) -> Vec<T>
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_concat() {
+        let storage: [[u8; PART_LEN]; PARTS] = kani::any();
+        let (parts, count) = any_parts(&storage);
+        let parts = &parts[..count];
+
+        let result = parts.concat();
+        assert_eq!(result.len(), parts.iter().map(|part| part.len()).sum::<usize>());
+        check_interleaved(&result, parts, &[]);
+    }
+
+    // fn join(slice: &Self, sep: &[T]) -> Vec<T>
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_join_slice() {
+        let storage: [[u8; PART_LEN]; PARTS] = kani::any();
+        let (parts, count) = any_parts(&storage);
+        let parts = &parts[..count];
+        // Includes the zero-length separator.
+        let sep_storage: [u8; 2] = kani::any();
+        let sep = &sep_storage[..kani::any_where(|len: &usize| *len <= 2)];
+
+        let result = parts.join(sep);
+        let separators = count.saturating_sub(1) * sep.len();
+        assert_eq!(result.len(), parts.iter().map(|part| part.len()).sum::<usize>() + separators);
+        check_interleaved(&result, parts, sep);
+    }
+
+    // fn join(slice: &Self, sep: &T) -> Vec<T>
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_join_element() {
+        let storage: [[u8; PART_LEN]; PARTS] = kani::any();
+        let (parts, count) = any_parts(&storage);
+        let parts = &parts[..count];
+        let sep: u8 = kani::any();
+
+        let result = parts.join(&sep);
+        let separators = count.saturating_sub(1);
+        assert_eq!(result.len(), parts.iter().map(|part| part.len()).sum::<usize>() + separators);
+        check_interleaved(&result, parts, &[sep]);
+    }
+}
@@ -49,6 +49,8 @@ pub use core::str::{from_raw_parts, from_raw_parts_mut};
 use core::unicode::conversions;
 use core::{mem, ptr};
 
+use safety::requires;
+
 use crate::borrow::ToOwned;
 use crate::boxed::Box;
 use crate::slice::{Concat, Join, SliceIndex};
@@ -615,6 +617,7 @@ impl str {
 #[stable(feature = "str_box_extras", since = "1.20.0")]
 #[must_use]
 #[inline]
+#[requires(core::str::from_utf8(&v).is_ok())]
 pub unsafe fn from_boxed_utf8_unchecked(v: Box<[u8]>) -> Box<str> {
     unsafe { Box::from_raw(Box::into_raw(v) as *mut str) }
 }
@@ -714,3 +717,31 @@ unsafe fn replace_ascii(utf8_bytes: &[u8], from: u8, to: u8) -> String {
     // SAFETY: We replaced ascii with ascii on valid utf8 strings.
     unsafe { String::from_utf8_unchecked(result) }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::kani;
+    use core::verify_support::any_str_within;
+
+    use super::from_boxed_utf8_unchecked;
+    use crate::boxed::Box;
+
+    #[kani::proof_for_contract(from_boxed_utf8_unchecked)]
+    #[kani::unwind(9)]
+    pub fn verify_from_boxed_utf8_unchecked() {
+        let mut storage = [0u8; 8];
+        let original = any_str_within(&mut storage);
+        let bytes: Box<[u8]> = Box::from(original.as_bytes());
+        let ptr = bytes.as_ptr();
+
+        let s = unsafe { from_boxed_utf8_unchecked(bytes) };
+        assert!(s.as_bytes() == original.as_bytes());
+        assert!(s.as_ptr() == ptr);
+
+        // Converting back keeps the same allocation and contents
+        let bytes = s.into_boxed_bytes();
+        assert!(bytes.as_ptr() == ptr);
+        assert!(*bytes == *original.as_bytes());
+    }
+}
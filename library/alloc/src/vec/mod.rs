@@ -66,6 +66,8 @@ use core::ops::{self, Index, IndexMut, Range, RangeBounds};
 use core::ptr::{self, NonNull};
 use core::slice::{self, SliceIndex};
 
+use safety::requires;
+
 #[unstable(feature = "extract_if", reason = "recently added", issue = "43244")]
 pub use self::extract_if::ExtractIf;
 use crate::alloc::{Allocator, Global};
@@ -602,6 +604,10 @@ impl<T> Vec<T> {
     /// ```
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
+    // That `ptr` came from the global allocator with a matching layout cannot be
+    // stated here; these are the parts of the invariant that can.
+    #[requires(length <= capacity)]
+    #[requires(!ptr.is_null() && ptr.is_aligned())]
     pub unsafe fn from_raw_parts(ptr: *mut T, length: usize, capacity: usize) -> Self {
         unsafe { Self::from_raw_parts_in(ptr, length, capacity, Global) }
     }
@@ -4079,4 +4085,37 @@ mod verify {
             assert!(vect[k] == arr[k]);
         }
     }
+
+    #[kani::proof_for_contract(Vec::<i32>::from_raw_parts)]
+    #[kani::unwind(5)]
+    pub fn verify_from_raw_parts_round_trip() {
+        let arr: [i32; ARRAY_LEN] = kani::Arbitrary::any_array();
+        let len: usize = kani::any_where(|x| *x <= ARRAY_LEN);
+        // Covers both the unallocated (zero capacity) and the allocated case
+        let mut vect = Vec::with_capacity(kani::any_where(|x| *x <= ARRAY_LEN + 1));
+        vect.extend_from_slice(&arr[..len]);
+        let capacity = vect.capacity();
+
+        let (ptr, length, cap) = vect.into_raw_parts();
+        assert!(length <= cap);
+        let rebuilt = unsafe { Vec::from_raw_parts(ptr, length, cap) };
+
+        // The rebuilt vector owns the same elements and allocation, which is
+        // freed exactly once when it is dropped here
+        assert!(rebuilt.len() == len);
+        assert!(rebuilt.capacity() == capacity);
+        assert!(rebuilt[..] == arr[..len]);
+    }
+
+    #[kani::proof_for_contract(Vec::<()>::from_raw_parts)]
+    pub fn verify_from_raw_parts_round_trip_zst() {
+        let len: usize = kani::any_where(|x| *x <= ARRAY_LEN);
+        let vect = Vec::from_iter(core::iter::repeat_n((), len));
+
+        let (ptr, length, cap) = vect.into_raw_parts();
+        let rebuilt = unsafe { Vec::from_raw_parts(ptr, length, cap) };
+
+        assert!(rebuilt.len() == len);
+        assert!(rebuilt.capacity() == usize::MAX);
+    }
 }
@@ -178,3 +178,52 @@ impl fmt::Debug for EscapeDefault {
         f.debug_struct("EscapeDefault").finish_non_exhaustive()
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::kani;
+
+    /// Reference un-escaper for the sequences produced by `escape_default`.
+    fn unescape(escaped: &[u8]) -> Option<u8> {
+        fn hex_digit(d: u8) -> Option<u8> {
+            match d {
+                b'0'..=b'9' => Some(d - b'0'),
+                b'a'..=b'f' => Some(d - b'a' + 10),
+                _ => None,
+            }
+        }
+
+        match *escaped {
+            [b] if !matches!(b, b'\\' | b'\'' | b'"') => Some(b),
+            [b'\\', b't'] => Some(b'\t'),
+            [b'\\', b'n'] => Some(b'\n'),
+            [b'\\', b'r'] => Some(b'\r'),
+            [b'\\', b @ (b'\\' | b'\'' | b'"')] => Some(b),
+            [b'\\', b'x', hi, lo] => Some(hex_digit(hi)? << 4 | hex_digit(lo)?),
+            _ => None,
+        }
+    }
+
+    // pub fn escape_default(c: u8) -> EscapeDefault
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_escape_default() {
+        let c: u8 = kani::any();
+        let escape = escape_default(c);
+        let len = escape.len();
+        assert!(len >= 1 && len <= 4);
+
+        let mut buf = [0u8; 4];
+        for (i, b) in escape.enumerate() {
+            buf[i] = b;
+        }
+        let escaped = &buf[..len];
+
+        // Every produced byte is printable ASCII.
+        assert!(escaped.iter().all(|b| (b' '..=b'~').contains(b)));
+        assert_eq!(unescape(escaped), Some(c));
+        assert_eq!(c.escape_ascii().len(), len);
+    }
+}
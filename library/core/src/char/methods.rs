@@ -1892,4 +1892,95 @@ mod verify {
         let non_ascii: char = kani::any_where(|c: &char| !c.is_ascii());
         as_ascii_clone(&non_ascii);
     }
+
+    // pub const fn to_ascii_uppercase(&self) -> char
+    // pub const fn to_ascii_lowercase(&self) -> char
+    #[kani::proof]
+    fn check_to_ascii_case() {
+        let c: char = kani::any();
+        let upper = c.to_ascii_uppercase();
+        let lower = c.to_ascii_lowercase();
+
+        match c {
+            'a'..='z' => {
+                assert_eq!(upper as u32, c as u32 - 32);
+                assert_eq!(lower, c);
+            }
+            'A'..='Z' => {
+                assert_eq!(upper, c);
+                assert_eq!(lower as u32, c as u32 + 32);
+            }
+            _ => {
+                assert_eq!(upper, c);
+                assert_eq!(lower, c);
+            }
+        }
+
+        assert_eq!(lower.to_ascii_uppercase(), upper);
+        assert_eq!(upper.to_ascii_lowercase(), lower);
+        if c.is_ascii() {
+            assert_eq!(upper as u8, (c as u8).to_ascii_uppercase());
+            assert_eq!(lower as u8, (c as u8).to_ascii_lowercase());
+        }
+    }
+
+    // pub fn encode_utf8(self, dst: &mut [u8]) -> &mut str
+    #[kani::proof]
+    fn check_encode_utf8() {
+        let c: char = kani::any();
+        let mut buf = [0u8; 4];
+        let encoded = c.encode_utf8(&mut buf);
+        let len = encoded.len();
+        assert_eq!(len, c.len_utf8());
+
+        let expected = match c as u32 {
+            0..0x80 => 1,
+            0x80..0x800 => 2,
+            0x800..0x10000 => 3,
+            _ => 4,
+        };
+        assert_eq!(len, expected);
+
+        // Decoding the written bytes gives back exactly `c`.
+        let decoded = crate::str::from_utf8(&buf[..len]).unwrap();
+        let mut chars = decoded.chars();
+        assert_eq!(chars.next(), Some(c));
+        assert_eq!(chars.next(), None);
+
+        kani::cover!(c as u32 == 0xFFFF && len == 3);
+        kani::cover!(c as u32 == 0x10000 && len == 4);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_encode_utf8_short_buffer() {
+        let c: char = kani::any();
+        let mut buf = [0u8; 3];
+        let len = kani::any_where(|len: &usize| *len < c.len_utf8());
+        c.encode_utf8(&mut buf[..len]);
+    }
+
+    // pub const fn len_utf8(self) -> usize
+    // pub const fn len_utf16(self) -> usize
+    #[kani::proof]
+    fn check_len_utf8_utf16() {
+        let c: char = kani::any();
+        assert_eq!(c.len_utf8(), c.encode_utf8(&mut [0u8; 4]).len());
+
+        let mut buf = [0u16; 2];
+        let units = c.encode_utf16(&mut buf);
+        assert_eq!(c.len_utf16(), units.len());
+        if (c as u32) < 0x10000 {
+            assert_eq!(units.len(), 1);
+            assert_eq!(units[0] as u32, c as u32);
+        } else {
+            // Supplementary planes need a high/low surrogate pair.
+            assert_eq!(units.len(), 2);
+            assert!(matches!(units[0], 0xD800..=0xDBFF));
+            assert!(matches!(units[1], 0xDC00..=0xDFFF));
+            let high = (units[0] as u32) & 0x3FF;
+            let low = (units[1] as u32) & 0x3FF;
+            assert_eq!(0x10000 + (high << 10) + low, c as u32);
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use safety::{ensures, requires};
+
 use crate::num::TryFromIntError;
 
 mod private {
@@ -25,6 +27,20 @@ macro_rules! impl_float_to_int {
             #[unstable(feature = "convert_float_to_int", issue = "67057")]
             impl FloatToInt<$Int> for $Float {
                 #[inline]
+                #[requires(self.is_finite())]
+                #[requires(crate::ub_checks::float_to_int_in_range::<$Float, $Int>(self))]
+                // The conversion truncates towards zero: `0.0` and `-0.0` both become `0`, and
+                // otherwise the result differs from `self` by its fractional part. A truncated
+                // float is representable in the float type, so that difference is exact.
+                #[ensures(|result| self != 0.0 || *result == 0)]
+                #[ensures(|result| {
+                    let fract = self - *result as $Float;
+                    if self > 0.0 {
+                        0.0 <= fract && fract < 1.0
+                    } else {
+                        -1.0 < fract && fract <= 0.0
+                    }
+                })]
                 unsafe fn to_int_unchecked(self) -> $Int {
                     // SAFETY: the safety contract must be upheld by the caller.
                     unsafe { crate::intrinsics::float_to_int_unchecked(self) }
@@ -540,3 +556,732 @@ impl_nonzero_int_try_from_nonzero_int!(i32 => u8, u16, u32, u64, u128, usize);
 impl_nonzero_int_try_from_nonzero_int!(i64 => u8, u16, u32, u64, u128, usize);
 impl_nonzero_int_try_from_nonzero_int!(i128 => u8, u16, u32, u64, u128, usize);
 impl_nonzero_int_try_from_nonzero_int!(isize => u8, u16, u32, u64, u128, usize);
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::kani;
+
+    // Verify that `From<NonZero<$Small>>` for `NonZero<$Large>` preserves the
+    // numeric value. For unsigned sources this means zero extension: the
+    // result must stay positive even when the top bit of the source is set.
+    macro_rules! generate_nonzero_int_from_nonzero_int_harness {
+        ($Small:ty => $Large:ty, $harness:ident) => {
+            #[kani::proof]
+            pub fn $harness() {
+                let x: NonZero<$Small> = kani::any();
+                let result = NonZero::<$Large>::from(x);
+                assert_eq!(result.get(), <$Large>::from(x.get()));
+                assert!(result.get() != 0);
+                if <$Small>::MIN == 0 {
+                    assert!(result.get() > 0);
+                }
+            }
+        };
+    }
+
+    // non-zero unsigned -> non-zero signed integer
+    generate_nonzero_int_from_nonzero_int_harness!(u8 => i16, check_nonzero_i16_from_nonzero_u8);
+    generate_nonzero_int_from_nonzero_int_harness!(u8 => i32, check_nonzero_i32_from_nonzero_u8);
+    generate_nonzero_int_from_nonzero_int_harness!(u8 => i64, check_nonzero_i64_from_nonzero_u8);
+    generate_nonzero_int_from_nonzero_int_harness!(u8 => i128, check_nonzero_i128_from_nonzero_u8);
+    generate_nonzero_int_from_nonzero_int_harness!(
+        u8 => isize,
+        check_nonzero_isize_from_nonzero_u8
+    );
+    generate_nonzero_int_from_nonzero_int_harness!(u16 => i32, check_nonzero_i32_from_nonzero_u16);
+    generate_nonzero_int_from_nonzero_int_harness!(u16 => i64, check_nonzero_i64_from_nonzero_u16);
+    generate_nonzero_int_from_nonzero_int_harness!(
+        u16 => i128,
+        check_nonzero_i128_from_nonzero_u16
+    );
+    generate_nonzero_int_from_nonzero_int_harness!(u32 => i64, check_nonzero_i64_from_nonzero_u32);
+    generate_nonzero_int_from_nonzero_int_harness!(
+        u32 => i128,
+        check_nonzero_i128_from_nonzero_u32
+    );
+    generate_nonzero_int_from_nonzero_int_harness!(
+        u64 => i128,
+        check_nonzero_i128_from_nonzero_u64
+    );
+
+    // Verify `TryFrom<NonZero<$source>>` for `NonZero<$target>`: the conversion
+    // succeeds exactly when the value fits in `$target`, and then preserves it.
+    macro_rules! generate_nonzero_int_try_from_nonzero_int_harness {
+        ($source:ty => $($target:ty, $harness:ident),+) => {$(
+            #[kani::proof]
+            pub fn $harness() {
+                let x: NonZero<$source> = kani::any();
+                let result = NonZero::<$target>::try_from(x);
+                match <$target>::try_from(x.get()) {
+                    Ok(v) => assert_eq!(result.map(NonZero::get), Ok(v)),
+                    Err(_) => assert!(result.is_err()),
+                }
+            }
+        )+};
+    }
+
+    // unsigned non-zero integer -> unsigned non-zero integer
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        u16 => u8,
+        check_nonzero_u8_try_from_nonzero_u16
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        u32 => u8,
+        check_nonzero_u8_try_from_nonzero_u32,
+        u16,
+        check_nonzero_u16_try_from_nonzero_u32,
+        usize,
+        check_nonzero_usize_try_from_nonzero_u32
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        u64 => u8,
+        check_nonzero_u8_try_from_nonzero_u64,
+        u16,
+        check_nonzero_u16_try_from_nonzero_u64,
+        u32,
+        check_nonzero_u32_try_from_nonzero_u64,
+        usize,
+        check_nonzero_usize_try_from_nonzero_u64
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        u128 => u8,
+        check_nonzero_u8_try_from_nonzero_u128,
+        u16,
+        check_nonzero_u16_try_from_nonzero_u128,
+        u32,
+        check_nonzero_u32_try_from_nonzero_u128,
+        u64,
+        check_nonzero_u64_try_from_nonzero_u128,
+        usize,
+        check_nonzero_usize_try_from_nonzero_u128
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        usize => u8,
+        check_nonzero_u8_try_from_nonzero_usize,
+        u16,
+        check_nonzero_u16_try_from_nonzero_usize,
+        u32,
+        check_nonzero_u32_try_from_nonzero_usize,
+        u64,
+        check_nonzero_u64_try_from_nonzero_usize,
+        u128,
+        check_nonzero_u128_try_from_nonzero_usize
+    );
+
+    // signed non-zero integer -> signed non-zero integer
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        i16 => i8,
+        check_nonzero_i8_try_from_nonzero_i16
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        i32 => i8,
+        check_nonzero_i8_try_from_nonzero_i32,
+        i16,
+        check_nonzero_i16_try_from_nonzero_i32,
+        isize,
+        check_nonzero_isize_try_from_nonzero_i32
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        i64 => i8,
+        check_nonzero_i8_try_from_nonzero_i64,
+        i16,
+        check_nonzero_i16_try_from_nonzero_i64,
+        i32,
+        check_nonzero_i32_try_from_nonzero_i64,
+        isize,
+        check_nonzero_isize_try_from_nonzero_i64
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        i128 => i8,
+        check_nonzero_i8_try_from_nonzero_i128,
+        i16,
+        check_nonzero_i16_try_from_nonzero_i128,
+        i32,
+        check_nonzero_i32_try_from_nonzero_i128,
+        i64,
+        check_nonzero_i64_try_from_nonzero_i128,
+        isize,
+        check_nonzero_isize_try_from_nonzero_i128
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        isize => i8,
+        check_nonzero_i8_try_from_nonzero_isize,
+        i16,
+        check_nonzero_i16_try_from_nonzero_isize,
+        i32,
+        check_nonzero_i32_try_from_nonzero_isize,
+        i64,
+        check_nonzero_i64_try_from_nonzero_isize,
+        i128,
+        check_nonzero_i128_try_from_nonzero_isize
+    );
+
+    // unsigned non-zero integer -> signed non-zero integer
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        u8 => i8,
+        check_nonzero_i8_try_from_nonzero_u8
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        u16 => i8,
+        check_nonzero_i8_try_from_nonzero_u16,
+        i16,
+        check_nonzero_i16_try_from_nonzero_u16,
+        isize,
+        check_nonzero_isize_try_from_nonzero_u16
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        u32 => i8,
+        check_nonzero_i8_try_from_nonzero_u32,
+        i16,
+        check_nonzero_i16_try_from_nonzero_u32,
+        i32,
+        check_nonzero_i32_try_from_nonzero_u32,
+        isize,
+        check_nonzero_isize_try_from_nonzero_u32
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        u64 => i8,
+        check_nonzero_i8_try_from_nonzero_u64,
+        i16,
+        check_nonzero_i16_try_from_nonzero_u64,
+        i32,
+        check_nonzero_i32_try_from_nonzero_u64,
+        i64,
+        check_nonzero_i64_try_from_nonzero_u64,
+        isize,
+        check_nonzero_isize_try_from_nonzero_u64
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        u128 => i8,
+        check_nonzero_i8_try_from_nonzero_u128,
+        i16,
+        check_nonzero_i16_try_from_nonzero_u128,
+        i32,
+        check_nonzero_i32_try_from_nonzero_u128,
+        i64,
+        check_nonzero_i64_try_from_nonzero_u128,
+        i128,
+        check_nonzero_i128_try_from_nonzero_u128,
+        isize,
+        check_nonzero_isize_try_from_nonzero_u128
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        usize => i8,
+        check_nonzero_i8_try_from_nonzero_usize,
+        i16,
+        check_nonzero_i16_try_from_nonzero_usize,
+        i32,
+        check_nonzero_i32_try_from_nonzero_usize,
+        i64,
+        check_nonzero_i64_try_from_nonzero_usize,
+        i128,
+        check_nonzero_i128_try_from_nonzero_usize,
+        isize,
+        check_nonzero_isize_try_from_nonzero_usize
+    );
+
+    // signed non-zero integer -> unsigned non-zero integer
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        i8 => u8,
+        check_nonzero_u8_try_from_nonzero_i8,
+        u16,
+        check_nonzero_u16_try_from_nonzero_i8,
+        u32,
+        check_nonzero_u32_try_from_nonzero_i8,
+        u64,
+        check_nonzero_u64_try_from_nonzero_i8,
+        u128,
+        check_nonzero_u128_try_from_nonzero_i8,
+        usize,
+        check_nonzero_usize_try_from_nonzero_i8
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        i16 => u8,
+        check_nonzero_u8_try_from_nonzero_i16,
+        u16,
+        check_nonzero_u16_try_from_nonzero_i16,
+        u32,
+        check_nonzero_u32_try_from_nonzero_i16,
+        u64,
+        check_nonzero_u64_try_from_nonzero_i16,
+        u128,
+        check_nonzero_u128_try_from_nonzero_i16,
+        usize,
+        check_nonzero_usize_try_from_nonzero_i16
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        i32 => u8,
+        check_nonzero_u8_try_from_nonzero_i32,
+        u16,
+        check_nonzero_u16_try_from_nonzero_i32,
+        u32,
+        check_nonzero_u32_try_from_nonzero_i32,
+        u64,
+        check_nonzero_u64_try_from_nonzero_i32,
+        u128,
+        check_nonzero_u128_try_from_nonzero_i32,
+        usize,
+        check_nonzero_usize_try_from_nonzero_i32
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        i64 => u8,
+        check_nonzero_u8_try_from_nonzero_i64,
+        u16,
+        check_nonzero_u16_try_from_nonzero_i64,
+        u32,
+        check_nonzero_u32_try_from_nonzero_i64,
+        u64,
+        check_nonzero_u64_try_from_nonzero_i64,
+        u128,
+        check_nonzero_u128_try_from_nonzero_i64,
+        usize,
+        check_nonzero_usize_try_from_nonzero_i64
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        i128 => u8,
+        check_nonzero_u8_try_from_nonzero_i128,
+        u16,
+        check_nonzero_u16_try_from_nonzero_i128,
+        u32,
+        check_nonzero_u32_try_from_nonzero_i128,
+        u64,
+        check_nonzero_u64_try_from_nonzero_i128,
+        u128,
+        check_nonzero_u128_try_from_nonzero_i128,
+        usize,
+        check_nonzero_usize_try_from_nonzero_i128
+    );
+    generate_nonzero_int_try_from_nonzero_int_harness!(
+        isize => u8,
+        check_nonzero_u8_try_from_nonzero_isize,
+        u16,
+        check_nonzero_u16_try_from_nonzero_isize,
+        u32,
+        check_nonzero_u32_try_from_nonzero_isize,
+        u64,
+        check_nonzero_u64_try_from_nonzero_isize,
+        u128,
+        check_nonzero_u128_try_from_nonzero_isize,
+        usize,
+        check_nonzero_usize_try_from_nonzero_isize
+    );
+
+    // Conversions between identical types are not generated by
+    // `impl_nonzero_int_try_from_nonzero_int!` but come from the blanket
+    // `impl<T, U: Into<T>> TryFrom<U> for T`. They cannot fail and must return
+    // the input unchanged.
+    macro_rules! generate_nonzero_int_try_from_same_harness {
+        ($($t:ty, $harness:ident),+) => {$(
+            #[kani::proof]
+            pub fn $harness() {
+                let x: NonZero<$t> = kani::any();
+                let result: Result<NonZero<$t>, crate::convert::Infallible> =
+                    NonZero::<$t>::try_from(x);
+                assert_eq!(result, Ok(x));
+            }
+        )+};
+    }
+
+    generate_nonzero_int_try_from_same_harness!(
+        u8,
+        check_nonzero_u8_try_from_nonzero_u8,
+        u16,
+        check_nonzero_u16_try_from_nonzero_u16,
+        u32,
+        check_nonzero_u32_try_from_nonzero_u32,
+        u64,
+        check_nonzero_u64_try_from_nonzero_u64,
+        u128,
+        check_nonzero_u128_try_from_nonzero_u128,
+        usize,
+        check_nonzero_usize_try_from_nonzero_usize,
+        i8,
+        check_nonzero_i8_try_from_nonzero_i8,
+        i16,
+        check_nonzero_i16_try_from_nonzero_i16,
+        i32,
+        check_nonzero_i32_try_from_nonzero_i32,
+        i64,
+        check_nonzero_i64_try_from_nonzero_i64,
+        i128,
+        check_nonzero_i128_try_from_nonzero_i128,
+        isize,
+        check_nonzero_isize_try_from_nonzero_isize
+    );
+
+    // Verify the conversions generated by `impl_try_from_both_bounded!`: they
+    // succeed exactly when the value lies in `[$target::MIN, $target::MAX]`,
+    // rejecting values on both sides of that range. The pairs below are the
+    // ones that do not depend on the pointer width.
+    //
+    // The reference range check is done in `i128`, which holds every value of
+    // the source and target types used here.
+    macro_rules! generate_try_from_both_bounded_harness {
+        ($source:ty => $($target:ty, $harness:ident),+) => {$(
+            #[kani::proof]
+            pub fn $harness() {
+                let x: $source = kani::any();
+                let in_range =
+                    x as i128 >= <$target>::MIN as i128 && x as i128 <= <$target>::MAX as i128;
+                match <$target>::try_from(x) {
+                    Ok(v) => {
+                        assert!(in_range);
+                        assert_eq!(v as i128, x as i128);
+                    }
+                    Err(_) => assert!(!in_range),
+                }
+
+                // The exact boundaries on both sides
+                let max = <$target>::MAX as $source;
+                let min = <$target>::MIN as $source;
+                assert_eq!(<$target>::try_from(max), Ok(<$target>::MAX));
+                assert!(<$target>::try_from(max + 1).is_err());
+                assert_eq!(<$target>::try_from(min), Ok(<$target>::MIN));
+                assert!(<$target>::try_from(min - 1).is_err());
+            }
+        )+};
+    }
+
+    generate_try_from_both_bounded_harness!(i16 => i8, check_i8_try_from_i16);
+    generate_try_from_both_bounded_harness!(
+        i32 => i8,
+        check_i8_try_from_i32,
+        i16,
+        check_i16_try_from_i32
+    );
+    generate_try_from_both_bounded_harness!(
+        i64 => i8,
+        check_i8_try_from_i64,
+        i16,
+        check_i16_try_from_i64,
+        i32,
+        check_i32_try_from_i64
+    );
+    generate_try_from_both_bounded_harness!(
+        i128 => i8,
+        check_i8_try_from_i128,
+        i16,
+        check_i16_try_from_i128,
+        i32,
+        check_i32_try_from_i128,
+        i64,
+        check_i64_try_from_i128
+    );
+    generate_try_from_both_bounded_harness!(i16 => u8, check_u8_try_from_i16);
+    generate_try_from_both_bounded_harness!(
+        i32 => u8,
+        check_u8_try_from_i32,
+        u16,
+        check_u16_try_from_i32
+    );
+    generate_try_from_both_bounded_harness!(
+        i64 => u8,
+        check_u8_try_from_i64,
+        u16,
+        check_u16_try_from_i64,
+        u32,
+        check_u32_try_from_i64
+    );
+    generate_try_from_both_bounded_harness!(
+        i128 => u8,
+        check_u8_try_from_i128,
+        u16,
+        check_u16_try_from_i128,
+        u32,
+        check_u32_try_from_i128,
+        u64,
+        check_u64_try_from_i128
+    );
+
+    // Verify `From<bool>` for the integer types: `false` is `0`, `true` is `1`.
+    macro_rules! generate_int_from_bool_harness {
+        ($($target:ty, $harness:ident),+) => {$(
+            #[kani::proof]
+            pub fn $harness() {
+                let b: bool = kani::any();
+                let result = <$target>::from(b);
+                assert_eq!(result, if b { 1 } else { 0 });
+            }
+        )+};
+    }
+
+    generate_int_from_bool_harness!(
+        u8,
+        check_u8_from_bool,
+        u16,
+        check_u16_from_bool,
+        u32,
+        check_u32_from_bool,
+        u64,
+        check_u64_from_bool,
+        u128,
+        check_u128_from_bool,
+        usize,
+        check_usize_from_bool,
+        i8,
+        check_i8_from_bool,
+        i16,
+        check_i16_from_bool,
+        i32,
+        check_i32_from_bool,
+        i64,
+        check_i64_from_bool,
+        i128,
+        check_i128_from_bool,
+        isize,
+        check_isize_from_bool
+    );
+
+    // Verify `From<bool>` for the float types: `false` is `+0.0` (not `-0.0`)
+    // and `true` is `1.0`.
+    macro_rules! generate_float_from_bool_harness {
+        ($($target:ty, $harness:ident),+) => {$(
+            #[kani::proof]
+            pub fn $harness() {
+                let b: bool = kani::any();
+                let result = <$target>::from(b);
+                if b {
+                    assert_eq!(result, 1.0);
+                } else {
+                    assert_eq!(result, 0.0);
+                }
+                assert!(result.is_sign_positive());
+                assert_eq!(result.to_bits(), (if b { 1.0 as $target } else { 0.0 }).to_bits());
+            }
+        )+};
+    }
+
+    generate_float_from_bool_harness!(f32, check_f32_from_bool, f64, check_f64_from_bool);
+
+    // Verify the lossless integer -> float `From` impls: every source value
+    // fits in the significand, so converting back gives the same integer.
+    macro_rules! generate_float_from_int_harness {
+        ($source:ty => $($target:ty, $harness:ident),+) => {$(
+            #[kani::proof]
+            pub fn $harness() {
+                let x: $source = kani::any();
+                let result = <$target>::from(x);
+                assert_eq!(result as $source, x);
+                assert_eq!(result as i64, x as i64);
+                assert_eq!(result, x as $target);
+            }
+        )+};
+    }
+
+    // signed integer -> float
+    generate_float_from_int_harness!(i8 => f32, check_f32_from_i8, f64, check_f64_from_i8);
+    generate_float_from_int_harness!(i16 => f32, check_f32_from_i16, f64, check_f64_from_i16);
+    generate_float_from_int_harness!(i32 => f64, check_f64_from_i32);
+
+    // unsigned integer -> float
+    generate_float_from_int_harness!(u8 => f32, check_f32_from_u8, f64, check_f64_from_u8);
+    generate_float_from_int_harness!(u16 => f32, check_f32_from_u16, f64, check_f64_from_u16);
+    generate_float_from_int_harness!(u32 => f64, check_f64_from_u32);
+
+    // Why there is no `From<i32>` for `f32` nor `From<i64>` for `f64`: the
+    // significand has 24 and 53 bits respectively, so the first integer that
+    // is not representable is `2^24 + 1` and `2^53 + 1`.
+    #[kani::proof]
+    pub fn check_wider_int_to_float_is_lossy() {
+        assert_eq!((1i32 << 24) as f32 as i32, 1 << 24);
+        assert_ne!(((1i32 << 24) + 1) as f32 as i32, (1 << 24) + 1);
+        assert_eq!((1i64 << 53) as f64 as i64, 1 << 53);
+        assert_ne!(((1i64 << 53) + 1) as f64 as i64, (1 << 53) + 1);
+    }
+
+    // Verify the float -> float widening `From` impls: the value is unchanged,
+    // which for non-NaN inputs means narrowing back gives the same bits, NaN
+    // stays NaN and infinities and signs are preserved.
+    macro_rules! generate_float_from_float_harness {
+        ($source:ty => $($target:ty, $harness:ident),+) => {$(
+            #[kani::proof]
+            pub fn $harness() {
+                let x: $source = kani::any();
+                let result = <$target>::from(x);
+                if x.is_nan() {
+                    assert!(result.is_nan());
+                } else {
+                    assert_eq!((result as $source).to_bits(), x.to_bits());
+                    assert_eq!(result.is_infinite(), x.is_infinite());
+                    assert_eq!(result.is_sign_negative(), x.is_sign_negative());
+                }
+                // Every source subnormal is a normal number in the wider type.
+                if x.is_subnormal() {
+                    assert!(result.is_normal());
+                }
+            }
+        )+};
+    }
+
+    generate_float_from_float_harness!(f16 => f64, check_f64_from_f16, f128, check_f128_from_f16);
+    generate_float_from_float_harness!(f32 => f64, check_f64_from_f32, f128, check_f128_from_f32);
+
+    // There is no `From<f16>` for `f32` (see the FIXME above the impls), but
+    // the `as` cast performs the same exact widening. Check it on subnormals,
+    // which become normal: an `f16` subnormal is `mantissa * 2^-24`.
+    #[kani::proof]
+    pub fn check_f16_subnormal_to_f32() {
+        let x: f16 = kani::any_where(|x: &f16| x.is_subnormal());
+        let result = x as f32;
+        assert!(result.is_normal());
+        let magnitude = (x.to_bits() & 0x03ff) as f32 * (1.0 / (1 << 24) as f32);
+        assert_eq!(result, if x.is_sign_negative() { -magnitude } else { magnitude });
+    }
+
+    // Verify the `FloatToInt::to_int_unchecked` contract, and in particular
+    // that both signed zeros convert to `0`.
+    macro_rules! generate_float_to_int_unchecked_harness {
+        ($Float:ty => $($Int:ty, $harness:ident, $zero_harness:ident),+) => {$(
+            #[kani::proof_for_contract(<$Float as FloatToInt<$Int>>::to_int_unchecked)]
+            pub fn $harness() {
+                let x: $Float = kani::any();
+                let _ = unsafe { <$Float as FloatToInt<$Int>>::to_int_unchecked(x) };
+            }
+
+            #[kani::proof_for_contract(<$Float as FloatToInt<$Int>>::to_int_unchecked)]
+            pub fn $zero_harness() {
+                let x: $Float = if kani::any() { 0.0 } else { -0.0 };
+                let result = unsafe { <$Float as FloatToInt<$Int>>::to_int_unchecked(x) };
+                assert_eq!(result, 0);
+            }
+        )+};
+    }
+
+    generate_float_to_int_unchecked_harness!(
+        f32 => i32,
+        check_f32_to_int_unchecked_i32,
+        check_f32_to_int_unchecked_i32_zero,
+        u32,
+        check_f32_to_int_unchecked_u32,
+        check_f32_to_int_unchecked_u32_zero
+    );
+    generate_float_to_int_unchecked_harness!(
+        f64 => i64,
+        check_f64_to_int_unchecked_i64,
+        check_f64_to_int_unchecked_i64_zero,
+        u64,
+        check_f64_to_int_unchecked_u64,
+        check_f64_to_int_unchecked_u64_zero
+    );
+    // Narrow targets, where most of the float range is out of bounds, and
+    // 128-bit targets, whose bounds are not exactly representable as floats.
+    generate_float_to_int_unchecked_harness!(
+        f32 => u8,
+        check_f32_to_int_unchecked_u8,
+        check_f32_to_int_unchecked_u8_zero,
+        i8,
+        check_f32_to_int_unchecked_i8,
+        check_f32_to_int_unchecked_i8_zero
+    );
+    generate_float_to_int_unchecked_harness!(
+        f64 => i128,
+        check_f64_to_int_unchecked_i128,
+        check_f64_to_int_unchecked_i128_zero,
+        u128,
+        check_f64_to_int_unchecked_u128,
+        check_f64_to_int_unchecked_u128_zero
+    );
+    // The contract is also attached to the `f16` and `f128` impls. `f16` tops
+    // out at 65504, which fits `u16` but not `i16`.
+    generate_float_to_int_unchecked_harness!(
+        f16 => i16,
+        check_f16_to_int_unchecked_i16,
+        check_f16_to_int_unchecked_i16_zero,
+        u16,
+        check_f16_to_int_unchecked_u16,
+        check_f16_to_int_unchecked_u16_zero
+    );
+    generate_float_to_int_unchecked_harness!(
+        f128 => i128,
+        check_f128_to_int_unchecked_i128,
+        check_f128_to_int_unchecked_i128_zero,
+        u128,
+        check_f128_to_int_unchecked_u128,
+        check_f128_to_int_unchecked_u128_zero
+    );
+
+    // Verify that `usize`/`isize` behave exactly like the fixed-width integer
+    // types of the target's pointer width, for the conversions in the
+    // `ptr_try_from_impls` modules above as well as for arithmetic. A cfg block
+    // that drifts from the actual width would make these disagree.
+    macro_rules! generate_pointer_width_harness {
+        ($Uint:ty, $Int:ty, $harness:ident) => {
+            #[kani::proof]
+            pub fn $harness() {
+                assert_eq!(usize::BITS, <$Uint>::BITS);
+                assert_eq!(isize::BITS, <$Int>::BITS);
+                assert_eq!(usize::MAX as $Uint, <$Uint>::MAX);
+                assert_eq!(isize::MIN as $Int, <$Int>::MIN);
+                assert_eq!(isize::MAX as $Int, <$Int>::MAX);
+
+                let x: usize = kani::any();
+                let y: usize = kani::any();
+                let (x_fixed, y_fixed) = (x as $Uint, y as $Uint);
+                assert_eq!(<$Uint>::try_from(x), Ok(x_fixed));
+                assert_eq!(usize::try_from(x_fixed), Ok(x));
+                assert_eq!(x.wrapping_add(y) as $Uint, x_fixed.wrapping_add(y_fixed));
+                assert_eq!(x.wrapping_mul(y) as $Uint, x_fixed.wrapping_mul(y_fixed));
+                assert_eq!(x.checked_add(y).map(|r| r as $Uint), x_fixed.checked_add(y_fixed));
+                assert_eq!(x.checked_sub(y).map(|r| r as $Uint), x_fixed.checked_sub(y_fixed));
+
+                let i: isize = kani::any();
+                let j: isize = kani::any();
+                let (i_fixed, j_fixed) = (i as $Int, j as $Int);
+                assert_eq!(<$Int>::try_from(i), Ok(i_fixed));
+                assert_eq!(isize::try_from(i_fixed), Ok(i));
+                assert_eq!(i.wrapping_add(j) as $Int, i_fixed.wrapping_add(j_fixed));
+                assert_eq!(i.checked_add(j).map(|r| r as $Int), i_fixed.checked_add(j_fixed));
+                assert_eq!(i.checked_div(j).map(|r| r as $Int), i_fixed.checked_div(j_fixed));
+
+                // Fallible conversions from other widths agree too
+                let w: u128 = kani::any();
+                assert_eq!(usize::try_from(w).map(|r| r as $Uint), <$Uint>::try_from(w));
+                let v: i128 = kani::any();
+                assert_eq!(isize::try_from(v).map(|r| r as $Int), <$Int>::try_from(v));
+                assert_eq!(usize::try_from(v).map(|r| r as $Uint), <$Uint>::try_from(v));
+            }
+        };
+    }
+
+    #[cfg(target_pointer_width = "16")]
+    generate_pointer_width_harness!(u16, i16, check_pointer_width_16);
+    #[cfg(target_pointer_width = "32")]
+    generate_pointer_width_harness!(u32, i32, check_pointer_width_32);
+    #[cfg(target_pointer_width = "64")]
+    generate_pointer_width_harness!(u64, i64, check_pointer_width_64);
+
+    // `isize => usize` fails exactly for negative values, and `usize => isize`
+    // exactly above `isize::MAX`, whatever the pointer width.
+    #[kani::proof]
+    pub fn check_usize_try_from_isize() {
+        let x: isize = kani::any();
+        match usize::try_from(x) {
+            Ok(v) => {
+                assert!(x >= 0);
+                assert_eq!(v as isize, x);
+            }
+            Err(_) => assert!(x < 0),
+        }
+        assert_eq!(usize::try_from(0isize), Ok(0));
+        assert_eq!(usize::try_from(isize::MAX), Ok(isize::MAX as usize));
+        assert!(usize::try_from(-1isize).is_err());
+        assert!(usize::try_from(isize::MIN).is_err());
+    }
+
+    #[kani::proof]
+    pub fn check_isize_try_from_usize() {
+        let x: usize = kani::any();
+        match isize::try_from(x) {
+            Ok(v) => {
+                assert!(x <= isize::MAX as usize);
+                assert_eq!(v as usize, x);
+            }
+            Err(_) => assert!(x > isize::MAX as usize),
+        }
+        assert_eq!(isize::try_from(isize::MAX as usize), Ok(isize::MAX));
+        assert!(isize::try_from(isize::MAX as usize + 1).is_err());
+        assert!(isize::try_from(usize::MAX).is_err());
+    }
+}
@@ -276,3 +276,46 @@ impl<T: ?Sized> DerefMut for ManuallyDrop<T> {
 
 #[unstable(feature = "deref_pure_trait", issue = "87121")]
 unsafe impl<T: ?Sized> DerefPure for ManuallyDrop<T> {}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::cell::Cell;
+    use crate::kani;
+    use crate::verify_support::DropCounter;
+
+    #[kani::proof]
+    fn check_manually_drop_suppresses_drop() {
+        let drops = Cell::new(0);
+        let slot = ManuallyDrop::new(DropCounter::new(kani::any::<u8>(), &drops));
+        drop(slot);
+        assert_eq!(drops.get(), 0);
+    }
+
+    #[kani::proof]
+    fn check_into_inner_restores_drop() {
+        let drops = Cell::new(0);
+        let value: u8 = kani::any();
+        let slot = ManuallyDrop::new(DropCounter::new(value, &drops));
+        let inner = ManuallyDrop::into_inner(slot);
+        assert_eq!(drops.get(), 0);
+        assert_eq!(inner.value, value);
+        drop(inner);
+        assert_eq!(drops.get(), 1);
+    }
+
+    // `ManuallyDrop::drop` runs the destructor once, and dropping the wrapper
+    // afterwards does not run it again. Calling `ManuallyDrop::drop` a second
+    // time would run it again on an already-dropped value, which is exactly
+    // the double drop its safety contract forbids.
+    #[kani::proof]
+    fn check_manually_drop_drop_runs_once() {
+        let drops = Cell::new(0);
+        let mut slot = ManuallyDrop::new(DropCounter::new(kani::any::<u8>(), &drops));
+        unsafe { ManuallyDrop::drop(&mut slot) };
+        assert_eq!(drops.get(), 1);
+        drop(slot);
+        assert_eq!(drops.get(), 1);
+    }
+}
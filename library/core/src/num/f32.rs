@@ -11,7 +11,7 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
-use safety::requires;
+use safety::{ensures, requires};
 
 use crate::convert::FloatToInt;
 #[cfg(not(test))]
@@ -1406,6 +1406,9 @@ impl f32 {
     #[stable(feature = "clamp", since = "1.50.0")]
     #[rustc_const_stable(feature = "const_float_methods", since = "CURRENT_RUSTC_VERSION")]
     #[inline]
+    // `min <= max` also rules out either bound being NaN.
+    #[requires(min <= max)]
+    #[ensures(|result| result.is_nan() || (*result >= min && *result <= max))]
     pub const fn clamp(mut self, min: f32, max: f32) -> f32 {
         const_assert!(
             min <= max,
@@ -1507,3 +1510,137 @@ impl f32 {
         unsafe { intrinsics::copysignf32(self, sign) }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // pub const fn classify(self) -> FpCategory
+    //
+    // The category must match the IEEE 754 bit layout, and every `is_*`
+    // predicate must agree with it.
+    #[kani::proof]
+    pub fn check_f32_classify() {
+        let x = f32::from_bits(kani::any::<u32>());
+        let category = x.classify();
+
+        let exp = x.to_bits() & f32::EXP_MASK;
+        let man = x.to_bits() & f32::MAN_MASK;
+        let expected = match (exp, man) {
+            (0, 0) => FpCategory::Zero,
+            (0, _) => FpCategory::Subnormal,
+            (f32::EXP_MASK, 0) => FpCategory::Infinite,
+            (f32::EXP_MASK, _) => FpCategory::Nan,
+            _ => FpCategory::Normal,
+        };
+        assert_eq!(category, expected);
+
+        assert_eq!(x.is_nan(), category == FpCategory::Nan);
+        assert_eq!(x.is_infinite(), category == FpCategory::Infinite);
+        assert_eq!(x.is_finite(), category != FpCategory::Nan && category != FpCategory::Infinite);
+        assert_eq!(x.is_normal(), category == FpCategory::Normal);
+        assert_eq!(x.is_subnormal(), category == FpCategory::Subnormal);
+    }
+
+    // The smallest positive normal number and the largest subnormal number
+    // are adjacent bit patterns.
+    #[kani::proof]
+    pub fn check_f32_classify_subnormal_boundary() {
+        let largest_subnormal = f32::from_bits(f32::MIN_POSITIVE.to_bits() - 1);
+        assert_eq!(f32::MIN_POSITIVE.classify(), FpCategory::Normal);
+        assert_eq!(largest_subnormal.classify(), FpCategory::Subnormal);
+        assert_eq!(f32::from_bits(1).classify(), FpCategory::Subnormal);
+        assert_eq!(0.0_f32.classify(), FpCategory::Zero);
+        assert_eq!((-0.0_f32).classify(), FpCategory::Zero);
+    }
+
+    // pub const fn abs(self) -> f32
+    //
+    // `abs` only clears the sign bit, so it is exact for every input, including
+    // NaNs, whose payload is preserved.
+    #[kani::proof]
+    pub fn check_f32_abs() {
+        let x = f32::from_bits(kani::any::<u32>());
+        assert_eq!(x.abs().to_bits(), x.to_bits() & !f32::SIGN_MASK);
+        assert!(x.abs().is_sign_positive());
+        assert_eq!((-0.0_f32).abs().to_bits(), 0.0_f32.to_bits());
+    }
+
+    // pub const fn copysign(self, sign: f32) -> f32
+    //
+    // The result has the magnitude of `self` and the sign of `sign`. This also
+    // holds when either operand is NaN: the sign bit of a NaN `sign` is carried
+    // over, and a NaN `self` keeps its payload.
+    #[kani::proof]
+    pub fn check_f32_copysign() {
+        let x = f32::from_bits(kani::any::<u32>());
+        let y = f32::from_bits(kani::any::<u32>());
+        let result = x.copysign(y);
+        assert_eq!(
+            result.to_bits(),
+            (x.to_bits() & !f32::SIGN_MASK) | (y.to_bits() & f32::SIGN_MASK)
+        );
+        assert_eq!(result.is_sign_negative(), y.is_sign_negative());
+        assert_eq!(0.0_f32.copysign(-0.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!((-0.0_f32).copysign(0.0).to_bits(), 0.0_f32.to_bits());
+    }
+
+    // pub const fn signum(self) -> f32
+    //
+    // Signed zeros map to `1.0` with the same sign, NaN maps to NaN.
+    #[kani::proof]
+    pub fn check_f32_signum() {
+        let x = f32::from_bits(kani::any::<u32>());
+        let result = x.signum();
+        if x.is_nan() {
+            assert!(result.is_nan());
+        } else if x.is_sign_negative() {
+            assert_eq!(result, -1.0);
+        } else {
+            assert_eq!(result, 1.0);
+        }
+        assert_eq!(0.0_f32.signum(), 1.0);
+        assert_eq!((-0.0_f32).signum(), -1.0);
+    }
+
+    // pub const fn clamp(mut self, min: f32, max: f32) -> f32
+    #[kani::proof_for_contract(f32::clamp)]
+    pub fn check_f32_clamp_contract() {
+        let x: f32 = kani::any();
+        let min: f32 = kani::any();
+        let max: f32 = kani::any();
+        let _ = x.clamp(min, max);
+    }
+
+    #[kani::proof]
+    pub fn check_f32_clamp() {
+        let x: f32 = kani::any();
+        let min: f32 = kani::any();
+        let max: f32 = kani::any();
+        kani::assume(min <= max);
+        let result = x.clamp(min, max);
+        if x.is_nan() {
+            assert!(result.is_nan());
+        } else {
+            assert!(result >= min && result <= max);
+            if x >= min && x <= max {
+                assert_eq!(result.to_bits(), x.to_bits());
+            }
+        }
+        // Signed zeros compare equal, so they are not normalized.
+        assert_eq!((-0.0_f32).clamp(0.0, 1.0).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(0.0_f32.clamp(-1.0, -0.0).to_bits(), 0.0_f32.to_bits());
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    pub fn check_f32_clamp_panics() {
+        let x: f32 = kani::any();
+        let min: f32 = kani::any();
+        let max: f32 = kani::any();
+        // Either `min > max`, or at least one of them is NaN.
+        kani::assume(!(min <= max));
+        let _ = x.clamp(min, max);
+    }
+}
@@ -11,7 +11,7 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
-use safety::requires;
+use safety::{ensures, requires};
 
 use crate::convert::FloatToInt;
 #[cfg(not(test))]
@@ -1406,6 +1406,9 @@ impl f64 {
     #[stable(feature = "clamp", since = "1.50.0")]
     #[rustc_const_stable(feature = "const_float_methods", since = "CURRENT_RUSTC_VERSION")]
     #[inline]
+    // `min <= max` also rules out either bound being NaN.
+    #[requires(min <= max)]
+    #[ensures(|result| result.is_nan() || (*result >= min && *result <= max))]
     pub const fn clamp(mut self, min: f64, max: f64) -> f64 {
         const_assert!(
             min <= max,
@@ -1507,3 +1510,175 @@ impl f64 {
         unsafe { intrinsics::copysignf64(self, sign) }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // pub const fn classify(self) -> FpCategory
+    //
+    // The category must match the IEEE 754 bit layout, and every `is_*`
+    // predicate must agree with it.
+    #[kani::proof]
+    pub fn check_f64_classify() {
+        let x = f64::from_bits(kani::any::<u64>());
+        let category = x.classify();
+
+        let exp = x.to_bits() & f64::EXP_MASK;
+        let man = x.to_bits() & f64::MAN_MASK;
+        let expected = match (exp, man) {
+            (0, 0) => FpCategory::Zero,
+            (0, _) => FpCategory::Subnormal,
+            (f64::EXP_MASK, 0) => FpCategory::Infinite,
+            (f64::EXP_MASK, _) => FpCategory::Nan,
+            _ => FpCategory::Normal,
+        };
+        assert_eq!(category, expected);
+
+        assert_eq!(x.is_nan(), category == FpCategory::Nan);
+        assert_eq!(x.is_infinite(), category == FpCategory::Infinite);
+        assert_eq!(x.is_finite(), category != FpCategory::Nan && category != FpCategory::Infinite);
+        assert_eq!(x.is_normal(), category == FpCategory::Normal);
+        assert_eq!(x.is_subnormal(), category == FpCategory::Subnormal);
+    }
+
+    // The smallest positive normal number and the largest subnormal number
+    // are adjacent bit patterns.
+    #[kani::proof]
+    pub fn check_f64_classify_subnormal_boundary() {
+        let largest_subnormal = f64::from_bits(f64::MIN_POSITIVE.to_bits() - 1);
+        assert_eq!(f64::MIN_POSITIVE.classify(), FpCategory::Normal);
+        assert_eq!(largest_subnormal.classify(), FpCategory::Subnormal);
+        assert_eq!(f64::from_bits(1).classify(), FpCategory::Subnormal);
+        assert_eq!(0.0_f64.classify(), FpCategory::Zero);
+        assert_eq!((-0.0_f64).classify(), FpCategory::Zero);
+    }
+
+    // pub const fn abs(self) -> f64
+    //
+    // `abs` only clears the sign bit, so it is exact for every input, including
+    // NaNs, whose payload is preserved.
+    #[kani::proof]
+    pub fn check_f64_abs() {
+        let x = f64::from_bits(kani::any::<u64>());
+        assert_eq!(x.abs().to_bits(), x.to_bits() & !f64::SIGN_MASK);
+        assert!(x.abs().is_sign_positive());
+        assert_eq!((-0.0_f64).abs().to_bits(), 0.0_f64.to_bits());
+    }
+
+    // pub const fn copysign(self, sign: f64) -> f64
+    //
+    // The result has the magnitude of `self` and the sign of `sign`. This also
+    // holds when either operand is NaN: the sign bit of a NaN `sign` is carried
+    // over, and a NaN `self` keeps its payload.
+    #[kani::proof]
+    pub fn check_f64_copysign() {
+        let x = f64::from_bits(kani::any::<u64>());
+        let y = f64::from_bits(kani::any::<u64>());
+        let result = x.copysign(y);
+        assert_eq!(
+            result.to_bits(),
+            (x.to_bits() & !f64::SIGN_MASK) | (y.to_bits() & f64::SIGN_MASK)
+        );
+        assert_eq!(result.is_sign_negative(), y.is_sign_negative());
+        assert_eq!(0.0_f64.copysign(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!((-0.0_f64).copysign(0.0).to_bits(), 0.0_f64.to_bits());
+    }
+
+    // pub const fn signum(self) -> f64
+    //
+    // Signed zeros map to `1.0` with the same sign, NaN maps to NaN.
+    #[kani::proof]
+    pub fn check_f64_signum() {
+        let x = f64::from_bits(kani::any::<u64>());
+        let result = x.signum();
+        if x.is_nan() {
+            assert!(result.is_nan());
+        } else if x.is_sign_negative() {
+            assert_eq!(result, -1.0);
+        } else {
+            assert_eq!(result, 1.0);
+        }
+        assert_eq!(0.0_f64.signum(), 1.0);
+        assert_eq!((-0.0_f64).signum(), -1.0);
+    }
+
+    // pub const fn clamp(mut self, min: f64, max: f64) -> f64
+    #[kani::proof_for_contract(f64::clamp)]
+    pub fn check_f64_clamp_contract() {
+        let x: f64 = kani::any();
+        let min: f64 = kani::any();
+        let max: f64 = kani::any();
+        let _ = x.clamp(min, max);
+    }
+
+    #[kani::proof]
+    pub fn check_f64_clamp() {
+        let x: f64 = kani::any();
+        let min: f64 = kani::any();
+        let max: f64 = kani::any();
+        kani::assume(min <= max);
+        let result = x.clamp(min, max);
+        if x.is_nan() {
+            assert!(result.is_nan());
+        } else {
+            assert!(result >= min && result <= max);
+            if x >= min && x <= max {
+                assert_eq!(result.to_bits(), x.to_bits());
+            }
+        }
+        // Signed zeros compare equal, so they are not normalized.
+        assert_eq!((-0.0_f64).clamp(0.0, 1.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(0.0_f64.clamp(-1.0, -0.0).to_bits(), 0.0_f64.to_bits());
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    pub fn check_f64_clamp_panics() {
+        let x: f64 = kani::any();
+        let min: f64 = kani::any();
+        let max: f64 = kani::any();
+        // Either `min > max`, or at least one of them is NaN.
+        kani::assume(!(min <= max));
+        let _ = x.clamp(min, max);
+    }
+
+    // pub fn total_cmp(&self, other: &Self) -> crate::cmp::Ordering
+    //
+    // `total_cmp` must be a total order over all bit patterns, including NaNs
+    // and signed zeros.
+    #[kani::proof]
+    pub fn check_f64_total_cmp_total_order() {
+        use crate::cmp::Ordering;
+
+        let a = f64::from_bits(kani::any::<u64>());
+        let b = f64::from_bits(kani::any::<u64>());
+        let c = f64::from_bits(kani::any::<u64>());
+
+        // Antisymmetry.
+        assert_eq!(a.total_cmp(&b), b.total_cmp(&a).reverse());
+        // Only identical bit patterns compare equal, so any two values are ordered.
+        assert_eq!(a.total_cmp(&b) == Ordering::Equal, a.to_bits() == b.to_bits());
+        // Transitivity, which also covers NaNs in the middle.
+        if a.total_cmp(&b) != Ordering::Greater && b.total_cmp(&c) != Ordering::Greater {
+            assert_ne!(a.total_cmp(&c), Ordering::Greater);
+        }
+    }
+
+    #[kani::proof]
+    pub fn check_f64_total_cmp_special_values() {
+        use crate::cmp::Ordering;
+
+        assert_eq!((-0.0_f64).total_cmp(&0.0), Ordering::Less);
+
+        // Positive NaNs sort above infinity, negative NaNs below negative infinity.
+        let nan = f64::from_bits(kani::any::<u64>());
+        kani::assume(nan.is_nan());
+        if nan.is_sign_positive() {
+            assert_eq!(nan.total_cmp(&f64::INFINITY), Ordering::Greater);
+        } else {
+            assert_eq!(nan.total_cmp(&f64::NEG_INFINITY), Ordering::Less);
+        }
+    }
+}
@@ -1695,6 +1695,28 @@ mod verify {
     // Improve unchecked_mul performance for {32, 64, 128}-bit integer types
     // by adding upper and lower limits for inputs
     macro_rules! generate_unchecked_mul_intervals {
+        // Additionally check the product against division, which is only
+        // tractable over these bounded intervals.
+        (inverse $type:ty, $($harness_name:ident, $min:expr, $max:expr),+) => {
+            $(
+                #[kani::proof_for_contract($type::unchecked_mul)]
+                pub fn $harness_name() {
+                    let num1: $type = kani::any::<$type>();
+                    let num2: $type = kani::any::<$type>();
+
+                    kani::assume(num1 >= $min && num1 <= $max);
+                    kani::assume(num2 >= $min && num2 <= $max);
+
+                    let result = unsafe { num1.unchecked_mul(num2) };
+                    if num1 == 0 {
+                        assert_eq!(result, 0);
+                    } else {
+                        assert_eq!(result / num1, num2);
+                        assert_eq!(result % num1, 0);
+                    }
+                }
+            )+
+        };
         ($type:ty, $method:ident, $($harness_name:ident, $min:expr, $max:expr),+) => {
             $(
                 #[kani::proof_for_contract($type::$method)]
@@ -1713,7 +1735,7 @@ mod verify {
                     }
                 }
             )+
-        }
+        };
     }
 
     // Verify `unchecked_{shl, shr}`
@@ -1845,6 +1867,36 @@ mod verify {
         }
     }
 
+    // Verify that the `to_int_unchecked` precondition, which relies on
+    // `float_to_int_in_range`, holds exactly when the truncated float fits in
+    // the integer type, i.e., it is neither too strict nor too lenient.
+    //
+    // The reference bounds are powers of two and therefore exact in every float
+    // type: `Int::MIN` is either zero or `-2^(BITS-1)`, and the exclusive upper
+    // bound `(Int::MAX / 2 + 1) * 2` is `Int::MAX + 1`. Bounds beyond the float's
+    // range become infinities, which still compare correctly with finite values.
+    macro_rules! generate_float_to_int_in_range_harness {
+        ($floatType:ty, $trunc:path, $($intType:ty, $harness_name:ident),+) => {
+            $(
+                #[kani::proof]
+                pub fn $harness_name() {
+                    let num1: $floatType = kani::any::<$floatType>();
+
+                    let precondition = num1.is_finite()
+                        && crate::ub_checks::float_to_int_in_range::<$floatType, $intType>(num1);
+
+                    let expected = num1.is_finite() && {
+                        let truncated = unsafe { $trunc(num1) };
+                        truncated >= <$intType>::MIN as $floatType
+                            && truncated < (<$intType>::MAX / 2 + 1) as $floatType * 2.0
+                    };
+
+                    assert_eq!(precondition, expected);
+                }
+            )+
+        }
+    }
+
     // `unchecked_add` proofs
     //
     // Target types:
@@ -2380,4 +2432,793 @@ mod verify {
         usize,
         checked_f128_to_int_unchecked_usize
     );
+
+    // `float_to_int_in_range` proofs
+    //
+    // Target integer types:
+    // i{8,16,32,64,128,size} and u{8,16,32,64,128,size} -- 12 types in total
+    //
+    // Target function:
+    // pub fn float_to_int_in_range<Float, Int>(value: Float) -> bool
+    generate_float_to_int_in_range_harness!(
+        f32,
+        crate::intrinsics::truncf32,
+        i8,
+        checked_f32_to_int_in_range_i8,
+        i16,
+        checked_f32_to_int_in_range_i16,
+        i32,
+        checked_f32_to_int_in_range_i32,
+        i64,
+        checked_f32_to_int_in_range_i64,
+        i128,
+        checked_f32_to_int_in_range_i128,
+        isize,
+        checked_f32_to_int_in_range_isize,
+        u8,
+        checked_f32_to_int_in_range_u8,
+        u16,
+        checked_f32_to_int_in_range_u16,
+        u32,
+        checked_f32_to_int_in_range_u32,
+        u64,
+        checked_f32_to_int_in_range_u64,
+        u128,
+        checked_f32_to_int_in_range_u128,
+        usize,
+        checked_f32_to_int_in_range_usize
+    );
+
+    generate_float_to_int_in_range_harness!(
+        f64,
+        crate::intrinsics::truncf64,
+        i8,
+        checked_f64_to_int_in_range_i8,
+        i16,
+        checked_f64_to_int_in_range_i16,
+        i32,
+        checked_f64_to_int_in_range_i32,
+        i64,
+        checked_f64_to_int_in_range_i64,
+        i128,
+        checked_f64_to_int_in_range_i128,
+        isize,
+        checked_f64_to_int_in_range_isize,
+        u8,
+        checked_f64_to_int_in_range_u8,
+        u16,
+        checked_f64_to_int_in_range_u16,
+        u32,
+        checked_f64_to_int_in_range_u32,
+        u64,
+        checked_f64_to_int_in_range_u64,
+        u128,
+        checked_f64_to_int_in_range_u128,
+        usize,
+        checked_f64_to_int_in_range_usize
+    );
+
+    // `f16` cannot even reach `u16::MAX`, so its wider bounds become
+    // infinities, while every integer type fits well within `f128`.
+    generate_float_to_int_in_range_harness!(
+        f16,
+        crate::intrinsics::truncf16,
+        i8,
+        checked_f16_to_int_in_range_i8,
+        i16,
+        checked_f16_to_int_in_range_i16,
+        i32,
+        checked_f16_to_int_in_range_i32,
+        i64,
+        checked_f16_to_int_in_range_i64,
+        i128,
+        checked_f16_to_int_in_range_i128,
+        isize,
+        checked_f16_to_int_in_range_isize,
+        u8,
+        checked_f16_to_int_in_range_u8,
+        u16,
+        checked_f16_to_int_in_range_u16,
+        u32,
+        checked_f16_to_int_in_range_u32,
+        u64,
+        checked_f16_to_int_in_range_u64,
+        u128,
+        checked_f16_to_int_in_range_u128,
+        usize,
+        checked_f16_to_int_in_range_usize
+    );
+
+    generate_float_to_int_in_range_harness!(
+        f128,
+        crate::intrinsics::truncf128,
+        i8,
+        checked_f128_to_int_in_range_i8,
+        i16,
+        checked_f128_to_int_in_range_i16,
+        i32,
+        checked_f128_to_int_in_range_i32,
+        i64,
+        checked_f128_to_int_in_range_i64,
+        i128,
+        checked_f128_to_int_in_range_i128,
+        isize,
+        checked_f128_to_int_in_range_isize,
+        u8,
+        checked_f128_to_int_in_range_u8,
+        u16,
+        checked_f128_to_int_in_range_u16,
+        u32,
+        checked_f128_to_int_in_range_u32,
+        u64,
+        checked_f128_to_int_in_range_u64,
+        u128,
+        checked_f128_to_int_in_range_u128,
+        usize,
+        checked_f128_to_int_in_range_usize
+    );
+
+    // `u8::to_ascii_{uppercase,lowercase}` proofs
+    //
+    // Only `a..=z` and `A..=Z` are transformed, and converting twice in any
+    // order is the same as converting once.
+    #[kani::proof]
+    pub fn check_u8_ascii_case_conversion() {
+        let x: u8 = kani::any();
+        let upper = x.to_ascii_uppercase();
+        let lower = x.to_ascii_lowercase();
+
+        match x {
+            b'a'..=b'z' => {
+                assert_eq!(upper, x - 32);
+                assert_eq!(lower, x);
+            }
+            b'A'..=b'Z' => {
+                assert_eq!(upper, x);
+                assert_eq!(lower, x + 32);
+            }
+            _ => {
+                assert_eq!(upper, x);
+                assert_eq!(lower, x);
+            }
+        }
+
+        assert_eq!(upper.to_ascii_uppercase(), upper);
+        assert_eq!(lower.to_ascii_lowercase(), lower);
+        assert_eq!(lower.to_ascii_uppercase(), upper);
+        assert_eq!(upper.to_ascii_lowercase(), lower);
+
+        // The bytes right outside the letter ranges are left alone.
+        for b in [b'@', b'[', b'`', b'{'] {
+            assert_eq!(b.to_ascii_uppercase(), b);
+            assert_eq!(b.to_ascii_lowercase(), b);
+        }
+    }
+
+    // `count_{ones,zeros}`, `leading_{zeros,ones}` and `trailing_{zeros,ones}`
+    // consistency proofs
+    //
+    // The ones and zeros partition the bits, and a run of leading (or
+    // trailing) bits is made of either zeros or ones, depending on the value of
+    // the most (or least) significant bit.
+    macro_rules! generate_bit_count_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                assert_eq!(x.count_ones() + x.count_zeros(), <$type>::BITS);
+                assert_eq!(x.leading_ones(), (!x).leading_zeros());
+                assert_eq!(x.trailing_ones(), (!x).trailing_zeros());
+
+                let top_set = x >> (<$type>::BITS - 1) == 1;
+                if top_set {
+                    assert_eq!(x.leading_zeros(), 0);
+                    assert!(x.leading_ones() >= 1);
+                } else {
+                    assert_eq!(x.leading_ones(), 0);
+                    assert!(x.leading_zeros() >= 1);
+                }
+                let bottom_set = x & 1 == 1;
+                if bottom_set {
+                    assert_eq!(x.trailing_zeros(), 0);
+                    assert!(x.trailing_ones() >= 1);
+                } else {
+                    assert_eq!(x.trailing_ones(), 0);
+                    assert!(x.trailing_zeros() >= 1);
+                }
+
+                // A leading (or trailing) run never exceeds the set or unset bits.
+                assert!(x.leading_ones() <= x.count_ones());
+                assert!(x.leading_zeros() <= x.count_zeros());
+                assert!(x.trailing_ones() <= x.count_ones());
+                assert!(x.trailing_zeros() <= x.count_zeros());
+
+                // `0b1010...10` alternates at every bit.
+                let alternating = <$type>::MAX / 3 * 2;
+                assert_eq!(alternating.count_ones(), <$type>::BITS / 2);
+                assert_eq!(alternating.leading_ones(), 1);
+                assert_eq!(alternating.leading_zeros(), 0);
+                assert_eq!(alternating.trailing_zeros(), 1);
+                assert_eq!(alternating.trailing_ones(), 0);
+            }
+        };
+    }
+
+    generate_bit_count_harness!(u8, check_bit_counts_u8);
+    generate_bit_count_harness!(u16, check_bit_counts_u16);
+    generate_bit_count_harness!(u32, check_bit_counts_u32);
+    generate_bit_count_harness!(u64, check_bit_counts_u64);
+    generate_bit_count_harness!(u128, check_bit_counts_u128);
+    generate_bit_count_harness!(usize, check_bit_counts_usize);
+
+    // `swap_bytes` and `reverse_bits` are involutions
+    macro_rules! generate_involution_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                assert_eq!(x.swap_bytes().swap_bytes(), x);
+                assert_eq!(x.reverse_bits().reverse_bits(), x);
+            }
+        };
+    }
+
+    generate_involution_harness!(u8, check_swap_reverse_involution_u8);
+    generate_involution_harness!(u16, check_swap_reverse_involution_u16);
+    generate_involution_harness!(u32, check_swap_reverse_involution_u32);
+    generate_involution_harness!(u64, check_swap_reverse_involution_u64);
+    generate_involution_harness!(u128, check_swap_reverse_involution_u128);
+    generate_involution_harness!(usize, check_swap_reverse_involution_usize);
+    generate_involution_harness!(i8, check_swap_reverse_involution_i8);
+    generate_involution_harness!(i16, check_swap_reverse_involution_i16);
+    generate_involution_harness!(i32, check_swap_reverse_involution_i32);
+    generate_involution_harness!(i64, check_swap_reverse_involution_i64);
+    generate_involution_harness!(i128, check_swap_reverse_involution_i128);
+    generate_involution_harness!(isize, check_swap_reverse_involution_isize);
+
+    // `swap_bytes` against a hand-written byte shuffle
+    #[kani::proof]
+    pub fn check_swap_bytes_u16_shuffle() {
+        let x: u16 = kani::any();
+        assert_eq!(x.swap_bytes(), (x << 8) | (x >> 8));
+    }
+
+    #[kani::proof]
+    pub fn check_swap_bytes_u32_shuffle() {
+        let x: u32 = kani::any();
+        let expected = ((x & 0x0000_00ff) << 24)
+            | ((x & 0x0000_ff00) << 8)
+            | ((x & 0x00ff_0000) >> 8)
+            | ((x & 0xff00_0000) >> 24);
+        assert_eq!(x.swap_bytes(), expected);
+    }
+
+    // `{to,from}_{be,le,ne}_bytes` round-trip proofs
+    macro_rules! generate_byte_conversion_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                assert_eq!(<$type>::from_be_bytes(x.to_be_bytes()), x);
+                assert_eq!(<$type>::from_le_bytes(x.to_le_bytes()), x);
+                assert_eq!(<$type>::from_ne_bytes(x.to_ne_bytes()), x);
+
+                let mut le = x.to_le_bytes();
+                le.reverse();
+                assert_eq!(x.to_be_bytes(), le);
+
+                #[cfg(target_endian = "little")]
+                assert_eq!(x.to_ne_bytes(), x.to_le_bytes());
+                #[cfg(target_endian = "big")]
+                assert_eq!(x.to_ne_bytes(), x.to_be_bytes());
+            }
+        };
+    }
+
+    generate_byte_conversion_harness!(u8, check_byte_conversion_u8);
+    generate_byte_conversion_harness!(u16, check_byte_conversion_u16);
+    generate_byte_conversion_harness!(u32, check_byte_conversion_u32);
+    generate_byte_conversion_harness!(u64, check_byte_conversion_u64);
+    generate_byte_conversion_harness!(u128, check_byte_conversion_u128);
+    generate_byte_conversion_harness!(usize, check_byte_conversion_usize);
+    generate_byte_conversion_harness!(i8, check_byte_conversion_i8);
+    generate_byte_conversion_harness!(i16, check_byte_conversion_i16);
+    generate_byte_conversion_harness!(i32, check_byte_conversion_i32);
+    generate_byte_conversion_harness!(i64, check_byte_conversion_i64);
+    generate_byte_conversion_harness!(i128, check_byte_conversion_i128);
+    generate_byte_conversion_harness!(isize, check_byte_conversion_isize);
+
+    // `checked_{shl,shr}` fail exactly when the shift amount is at least the
+    // bit width, and otherwise agree with `wrapping_{shl,shr}`.
+    macro_rules! generate_checked_shift_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                let n: u32 = kani::any();
+                if n >= <$type>::BITS {
+                    assert_eq!(x.checked_shl(n), None);
+                    assert_eq!(x.checked_shr(n), None);
+                } else {
+                    assert_eq!(x.checked_shl(n), Some(x.wrapping_shl(n)));
+                    assert_eq!(x.checked_shr(n), Some(x.wrapping_shr(n)));
+                }
+
+                // The precise edges
+                let last = <$type>::BITS - 1;
+                assert_eq!(x.checked_shl(last), Some(x << last));
+                assert_eq!(x.checked_shr(last), Some(x >> last));
+                assert_eq!(x.checked_shl(<$type>::BITS), None);
+                assert_eq!(x.checked_shr(<$type>::BITS), None);
+            }
+        };
+    }
+
+    generate_checked_shift_harness!(u8, check_checked_shift_u8);
+    generate_checked_shift_harness!(u16, check_checked_shift_u16);
+    generate_checked_shift_harness!(u32, check_checked_shift_u32);
+    generate_checked_shift_harness!(u64, check_checked_shift_u64);
+    generate_checked_shift_harness!(u128, check_checked_shift_u128);
+    generate_checked_shift_harness!(usize, check_checked_shift_usize);
+
+    // `wrapping_shl` shifts by the amount modulo the bit width
+    macro_rules! generate_wrapping_shl_mask_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                let n: u32 = kani::any();
+                let masked = n % <$type>::BITS;
+                assert_eq!(x.wrapping_shl(n), unsafe { x.unchecked_shl(masked) });
+                assert_eq!(x.wrapping_shl(n), x.wrapping_shl(masked));
+            }
+        };
+    }
+
+    generate_wrapping_shl_mask_harness!(u8, check_wrapping_shl_mask_u8);
+    generate_wrapping_shl_mask_harness!(u16, check_wrapping_shl_mask_u16);
+    generate_wrapping_shl_mask_harness!(u32, check_wrapping_shl_mask_u32);
+    generate_wrapping_shl_mask_harness!(u64, check_wrapping_shl_mask_u64);
+    generate_wrapping_shl_mask_harness!(u128, check_wrapping_shl_mask_u128);
+    generate_wrapping_shl_mask_harness!(usize, check_wrapping_shl_mask_usize);
+
+    // `>>` on signed integers is an arithmetic shift: it sign-extends, which
+    // makes it a division by `2^n` rounding towards negative infinity.
+    macro_rules! generate_signed_shr_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                let n: u32 = kani::any_where(|n: &u32| *n < <$type>::BITS);
+                let result = x >> n;
+
+                if n < <$type>::BITS - 1 {
+                    assert_eq!(result, x.div_euclid(1 << n));
+                } else {
+                    // `2^(BITS - 1)` is not representable, but every value is
+                    // smaller in magnitude than it.
+                    assert_eq!(result, if x < 0 { -1 } else { 0 });
+                }
+
+                if x < 0 {
+                    assert!(result < 0);
+                    assert!(result.leading_ones() >= n + 1);
+                } else {
+                    assert!(result >= 0);
+                    assert!(result.leading_zeros() >= n + 1);
+                }
+
+                assert_eq!((-1 as $type) >> n, -1);
+            }
+        };
+    }
+
+    generate_signed_shr_harness!(i8, check_signed_shr_i8);
+    generate_signed_shr_harness!(i16, check_signed_shr_i16);
+    generate_signed_shr_harness!(i32, check_signed_shr_i32);
+    generate_signed_shr_harness!(i64, check_signed_shr_i64);
+    generate_signed_shr_harness!(i128, check_signed_shr_i128);
+    generate_signed_shr_harness!(isize, check_signed_shr_isize);
+
+    // `checked_next_multiple_of` proofs
+    //
+    // The result is `None` exactly when `rhs == 0` or when rounding `x` up to
+    // a multiple of `rhs` overflows, and otherwise it is the least multiple of
+    // `rhs` that is at least `x`.
+    macro_rules! generate_checked_next_multiple_of_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                let rhs: $type = kani::any();
+                let result = x.checked_next_multiple_of(rhs);
+
+                if rhs == 0 {
+                    assert_eq!(result, None);
+                } else if x % rhs == 0 {
+                    // Already a multiple: returned as is, even near `MAX`.
+                    assert_eq!(result, Some(x));
+                } else {
+                    let expected = x.checked_add(rhs - x % rhs);
+                    assert_eq!(result, expected);
+                    match result {
+                        Some(m) => {
+                            assert!(m > x && m - x < rhs);
+                            assert_eq!(m % rhs, 0);
+                        }
+                        // The next multiple is past `MAX`.
+                        None => assert!(<$type>::MAX - x < rhs - x % rhs),
+                    }
+                }
+            }
+        };
+    }
+
+    generate_checked_next_multiple_of_harness!(u8, check_checked_next_multiple_of_u8);
+    generate_checked_next_multiple_of_harness!(u16, check_checked_next_multiple_of_u16);
+    generate_checked_next_multiple_of_harness!(u32, check_checked_next_multiple_of_u32);
+    generate_checked_next_multiple_of_harness!(u64, check_checked_next_multiple_of_u64);
+    generate_checked_next_multiple_of_harness!(u128, check_checked_next_multiple_of_u128);
+    generate_checked_next_multiple_of_harness!(usize, check_checked_next_multiple_of_usize);
+
+    // 128-bit `unchecked_{add,sub,mul}` proofs
+    //
+    // On many targets 128-bit arithmetic lowers to software routines, so
+    // check the results against references that never widen past 128 bits:
+    // a two-limb addition for `add` and `sub`, and division for `mul` over
+    // the bounded intervals below.
+    fn u128_add_by_limbs(a: u128, b: u128) -> (u128, bool) {
+        let (lo, carry) = (a as u64).overflowing_add(b as u64);
+        let (hi, overflow1) = ((a >> 64) as u64).overflowing_add((b >> 64) as u64);
+        let (hi, overflow2) = hi.overflowing_add(carry as u64);
+        (((hi as u128) << 64) | lo as u128, overflow1 || overflow2)
+    }
+
+    #[kani::proof_for_contract(u128::unchecked_add)]
+    pub fn check_u128_unchecked_add_by_limbs() {
+        let a: u128 = kani::any();
+        let b: u128 = kani::any();
+        let result = unsafe { a.unchecked_add(b) };
+        assert_eq!(u128_add_by_limbs(a, b), (result, false));
+    }
+
+    #[kani::proof_for_contract(u128::unchecked_add)]
+    pub fn check_u128_unchecked_add_near_max() {
+        let a: u128 = kani::any_where(|a: &u128| *a >= u128::MAX - 16);
+        let b: u128 = kani::any();
+        let result = unsafe { a.unchecked_add(b) };
+        assert_eq!(u128::MAX - result, u128::MAX - a - b);
+        kani::cover!(result == u128::MAX);
+    }
+
+    #[kani::proof_for_contract(u128::unchecked_sub)]
+    pub fn check_u128_unchecked_sub_by_limbs() {
+        let a: u128 = kani::any();
+        let b: u128 = kani::any();
+        let result = unsafe { a.unchecked_sub(b) };
+        assert_eq!(u128_add_by_limbs(result, b), (a, false));
+    }
+
+    #[kani::proof_for_contract(i128::unchecked_add)]
+    pub fn check_i128_unchecked_add_by_limbs() {
+        let a: i128 = kani::any();
+        let b: i128 = kani::any();
+        let result = unsafe { a.unchecked_add(b) };
+        // Two's complement addition is the unsigned one on the same bits
+        assert_eq!(result as u128, u128_add_by_limbs(a as u128, b as u128).0);
+    }
+
+    #[kani::proof_for_contract(i128::unchecked_sub)]
+    pub fn check_i128_unchecked_sub_by_limbs() {
+        let a: i128 = kani::any();
+        let b: i128 = kani::any();
+        let result = unsafe { a.unchecked_sub(b) };
+        assert_eq!(u128_add_by_limbs(result as u128, b as u128).0, a as u128);
+    }
+
+    // The middle intervals put both factors around `2^62`/`2^63`, so the
+    // product fills the high limb without overflowing.
+    generate_unchecked_mul_intervals!(
+        inverse u128,
+        unchecked_mul_u128_inverse_small,
+        0u128,
+        10u128,
+        unchecked_mul_u128_inverse_mid,
+        (1u128 << 63) - 8,
+        (1u128 << 63) + 8
+    );
+    generate_unchecked_mul_intervals!(
+        inverse i128,
+        unchecked_mul_i128_inverse_small,
+        -10i128,
+        10i128,
+        unchecked_mul_i128_inverse_mid_pos,
+        (1i128 << 62) - 8,
+        (1i128 << 62) + 8,
+        unchecked_mul_i128_inverse_mid_neg,
+        -(1i128 << 62) - 8,
+        -(1i128 << 62) + 8
+    );
+
+    // Mixed-sign `checked_add_signed` / `checked_sub_unsigned` proofs
+    //
+    // Both return `None` exactly when the mathematical result, computed in
+    // `i128`, falls outside the range of the target type.
+    macro_rules! generate_checked_mixed_sign_harness {
+        ($unsigned:ty, $signed:ty, $harness_name:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let u: $unsigned = kani::any();
+                let i: $signed = kani::any();
+
+                // pub const fn checked_add_signed(self, rhs: $SignedT) -> Option<Self>
+                let sum = u as i128 + i as i128;
+                let in_range = sum >= 0 && sum <= <$unsigned>::MAX as i128;
+                match u.checked_add_signed(i) {
+                    Some(r) => assert!(in_range && r as i128 == sum),
+                    None => assert!(!in_range),
+                }
+                // Adding a negative value larger in magnitude than `u` underflows.
+                kani::cover!(i < 0 && sum < 0);
+
+                // pub const fn checked_sub_unsigned(self, rhs: $UnsignedT) -> Option<Self>
+                let diff = i as i128 - u as i128;
+                let in_range = diff >= <$signed>::MIN as i128;
+                match i.checked_sub_unsigned(u) {
+                    Some(r) => assert!(in_range && r as i128 == diff),
+                    None => assert!(!in_range),
+                }
+            }
+        };
+    }
+
+    generate_checked_mixed_sign_harness!(u8, i8, check_checked_mixed_sign_8);
+    generate_checked_mixed_sign_harness!(u16, i16, check_checked_mixed_sign_16);
+    generate_checked_mixed_sign_harness!(u32, i32, check_checked_mixed_sign_32);
+    generate_checked_mixed_sign_harness!(u64, i64, check_checked_mixed_sign_64);
+    generate_checked_mixed_sign_harness!(usize, isize, check_checked_mixed_sign_size);
+
+    // `wrapping_add_signed` reduces the `i128` reference sum modulo `2^BITS`,
+    // while `saturating_add_signed` clamps it to `0..=MAX`.
+    macro_rules! generate_wrapping_saturating_add_signed_harness {
+        ($unsigned:ty, $signed:ty, $harness_name:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let u: $unsigned = kani::any();
+                let i: $signed = kani::any();
+                let sum = u as i128 + i as i128;
+                let modulus = 1i128 << <$unsigned>::BITS;
+
+                // pub const fn wrapping_add_signed(self, rhs: $SignedT) -> Self
+                let wrapped = u.wrapping_add_signed(i);
+                assert_eq!(wrapped as i128, sum.rem_euclid(modulus));
+
+                // pub const fn saturating_add_signed(self, rhs: $SignedT) -> Self
+                let saturated = u.saturating_add_signed(i);
+                assert_eq!(saturated as i128, sum.clamp(0, <$unsigned>::MAX as i128));
+
+                // The two agree unless the sum leaves the range, and when a
+                // negative `rhs` crosses zero they end up at opposite ends.
+                if sum < 0 {
+                    assert_eq!(saturated, 0);
+                    assert!(wrapped > u);
+                }
+                kani::cover!(sum < 0 && wrapped == <$unsigned>::MAX);
+            }
+        };
+    }
+
+    generate_wrapping_saturating_add_signed_harness!(u8, i8, check_add_signed_8);
+    generate_wrapping_saturating_add_signed_harness!(u16, i16, check_add_signed_16);
+    generate_wrapping_saturating_add_signed_harness!(u32, i32, check_add_signed_32);
+    generate_wrapping_saturating_add_signed_harness!(u64, i64, check_add_signed_64);
+    generate_wrapping_saturating_add_signed_harness!(usize, isize, check_add_signed_size);
+
+    // pub const fn signum(self) -> Self
+    macro_rules! generate_signum_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                let s = x.signum();
+                if x == 0 {
+                    assert_eq!(s, 0);
+                } else if x > 0 {
+                    assert_eq!(s, 1);
+                } else {
+                    assert_eq!(s, -1);
+                }
+                assert_eq!(<$type>::MIN.signum(), -1);
+                assert_eq!(<$type>::MAX.signum(), 1);
+            }
+        };
+    }
+
+    generate_signum_harness!(i8, check_signum_i8);
+    generate_signum_harness!(i16, check_signum_i16);
+    generate_signum_harness!(i32, check_signum_i32);
+    generate_signum_harness!(i64, check_signum_i64);
+    generate_signum_harness!(i128, check_signum_i128);
+    generate_signum_harness!(isize, check_signum_isize);
+
+    // pub const fn div_ceil(self, rhs: Self) -> Self
+    //
+    // Checked against the textbook `(x + rhs - 1) / rhs`, evaluated in `u128`
+    // so that the numerator cannot overflow near `MAX`.
+    macro_rules! generate_div_ceil_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof_for_contract($type::div_ceil)]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                let rhs: $type = kani::any();
+                let result = x.div_ceil(rhs);
+                assert_eq!(result as u128, (x as u128 + rhs as u128 - 1) / rhs as u128);
+                assert!(result >= x / rhs && result - x / rhs <= 1);
+                kani::cover!(x % rhs == 0 && result == x / rhs);
+                kani::cover!(x == <$type>::MAX && rhs > 1);
+            }
+        };
+    }
+
+    generate_div_ceil_harness!(u8, check_div_ceil_u8);
+    generate_div_ceil_harness!(u16, check_div_ceil_u16);
+    generate_div_ceil_harness!(u32, check_div_ceil_u32);
+    generate_div_ceil_harness!(u64, check_div_ceil_u64);
+    generate_div_ceil_harness!(usize, check_div_ceil_usize);
+
+    // There is no wider type to evaluate the textbook formula in, so check
+    // `u128` against the floor quotient and remainder instead, and that the
+    // result is the smallest multiplier of `rhs` reaching `x`.
+    #[kani::proof_for_contract(u128::div_ceil)]
+    pub fn check_div_ceil_u128() {
+        let x: u128 = kani::any();
+        let rhs: u128 = kani::any();
+        let result = x.div_ceil(rhs);
+        assert_eq!(result, x / rhs + (x % rhs != 0) as u128);
+        assert!(result.checked_mul(rhs).is_none_or(|p| p >= x));
+        assert!(result == 0 || (result - 1) * rhs < x);
+        kani::cover!(x % rhs == 0 && result == x / rhs);
+        kani::cover!(x == u128::MAX && rhs > 1);
+    }
+
+    // `checked_ilog` proofs
+    //
+    // `None` exactly when `x == 0` or `base < 2`; otherwise the result `n`
+    // is the unique exponent with `base^n <= x < base^(n + 1)` and agrees with
+    // the panicking `ilog`.
+    macro_rules! generate_checked_ilog_harness {
+        ($type:ty, $unwind:literal, $harness_name:ident) => {
+            #[kani::proof]
+            #[kani::unwind($unwind)]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                let base: $type = kani::any();
+                match x.checked_ilog(base) {
+                    None => assert!(x == 0 || base < 2),
+                    Some(n) => {
+                        assert!(x != 0 && base >= 2);
+                        assert!(base.pow(n) <= x);
+                        assert!(base.checked_pow(n + 1).is_none_or(|next| next > x));
+                        assert_eq!(x.ilog(base), n);
+                    }
+                }
+                assert_eq!(x.checked_ilog(0), None);
+                assert_eq!(x.checked_ilog(1), None);
+            }
+        };
+    }
+
+    generate_checked_ilog_harness!(u8, 10, check_checked_ilog_u8);
+    generate_checked_ilog_harness!(u16, 18, check_checked_ilog_u16);
+    generate_checked_ilog_harness!(u32, 34, check_checked_ilog_u32);
+
+    // `checked_ilog2` and `checked_ilog10` are `None` only for zero.
+    macro_rules! generate_checked_ilog2_ilog10_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            #[kani::unwind(8)]
+            pub fn $harness_name() {
+                let x: $type = kani::any();
+                match x.checked_ilog2() {
+                    None => assert_eq!(x, 0),
+                    Some(n) => {
+                        assert_eq!(n, <$type>::BITS - 1 - x.leading_zeros());
+                        assert_eq!(x.ilog2(), n);
+                    }
+                }
+                match x.checked_ilog10() {
+                    None => assert_eq!(x, 0),
+                    Some(n) => {
+                        assert!((10 as $type).pow(n) <= x);
+                        assert!((10 as $type).checked_pow(n + 1).is_none_or(|next| next > x));
+                        assert_eq!(x.ilog10(), n);
+                    }
+                }
+            }
+        };
+    }
+
+    generate_checked_ilog2_ilog10_harness!(u8, check_checked_ilog2_ilog10_u8);
+    generate_checked_ilog2_ilog10_harness!(u16, check_checked_ilog2_ilog10_u16);
+    generate_checked_ilog2_ilog10_harness!(u32, check_checked_ilog2_ilog10_u32);
+    generate_checked_ilog2_ilog10_harness!(u64, check_checked_ilog2_ilog10_u64);
+    generate_checked_ilog2_ilog10_harness!(u128, check_checked_ilog2_ilog10_u128);
+    generate_checked_ilog2_ilog10_harness!(usize, check_checked_ilog2_ilog10_usize);
+
+    // `Add` and `Sub` operator proofs
+    //
+    // With overflow checks on, as Kani models debug builds, the operators
+    // agree with `checked_{add,sub}` whenever those succeed and panic
+    // otherwise. The trait methods are called directly so that the proofs go
+    // through the operator impls rather than the inherent methods.
+    macro_rules! generate_add_sub_operator_harness {
+        ($type:ty, $harness_name:ident, $add_overflow:ident, $sub_overflow:ident) => {
+            #[kani::proof]
+            pub fn $harness_name() {
+                let a: $type = kani::any();
+                let b: $type = kani::any();
+                if let Some(sum) = a.checked_add(b) {
+                    assert_eq!(crate::ops::Add::add(a, b), sum);
+                }
+                if let Some(diff) = a.checked_sub(b) {
+                    assert_eq!(crate::ops::Sub::sub(a, b), diff);
+                }
+            }
+
+            #[kani::proof]
+            #[kani::should_panic]
+            pub fn $add_overflow() {
+                let a: $type = kani::any();
+                let b: $type = kani::any();
+                kani::assume(a.checked_add(b).is_none());
+                let _ = crate::ops::Add::add(a, b);
+            }
+
+            #[kani::proof]
+            #[kani::should_panic]
+            pub fn $sub_overflow() {
+                let a: $type = kani::any();
+                let b: $type = kani::any();
+                kani::assume(a.checked_sub(b).is_none());
+                let _ = crate::ops::Sub::sub(a, b);
+            }
+        };
+    }
+
+    generate_add_sub_operator_harness!(
+        i8,
+        check_add_sub_ops_i8,
+        check_add_overflow_i8,
+        check_sub_overflow_i8
+    );
+    generate_add_sub_operator_harness!(
+        i16,
+        check_add_sub_ops_i16,
+        check_add_overflow_i16,
+        check_sub_overflow_i16
+    );
+    generate_add_sub_operator_harness!(
+        i32,
+        check_add_sub_ops_i32,
+        check_add_overflow_i32,
+        check_sub_overflow_i32
+    );
+    generate_add_sub_operator_harness!(
+        i64,
+        check_add_sub_ops_i64,
+        check_add_overflow_i64,
+        check_sub_overflow_i64
+    );
+    generate_add_sub_operator_harness!(
+        i128,
+        check_add_sub_ops_i128,
+        check_add_overflow_i128,
+        check_sub_overflow_i128
+    );
+    generate_add_sub_operator_harness!(
+        isize,
+        check_add_sub_ops_isize,
+        check_add_overflow_isize,
+        check_sub_overflow_isize
+    );
 }
@@ -2303,11 +2303,15 @@ mod verify {
                 let x: $nonzero_type = kani::any();
                 let y: $nonzero_type = kani::any();
                 let result = x.max(y);
+                assert!(result.get() == x.get().max(y.get()));
                 if x > y {
                     assert!(result == x);
                 } else {
                     assert!(result == y);
                 }
+                // The smallest value (`iN::MIN` for signed types) is never the maximum
+                let min = <$nonzero_type>::MIN;
+                assert!(min.max(x) == x && x.max(min) == x);
             }
         };
     }
@@ -2332,11 +2336,15 @@ mod verify {
                 let x: $nonzero_type = kani::any();
                 let y: $nonzero_type = kani::any();
                 let result = x.min(y);
+                assert!(result.get() == x.get().min(y.get()));
                 if x < y {
                     assert!(result == x);
                 } else {
                     assert!(result == y);
                 }
+                // The smallest value (`iN::MIN` for signed types) is always the minimum
+                let min = <$nonzero_type>::MIN;
+                assert!(min.min(x) == min && x.min(min) == min);
             }
         };
     }
@@ -2435,6 +2443,8 @@ mod verify {
                 let result = x.count_ones();
                 // Since x is non-zero, count_ones should never return 0
                 assert!(result.get() > 0);
+                assert!(result.get() == x.get().count_ones());
+                assert!(result.get() <= <$nonzero_type>::BITS);
             }
         };
     }
@@ -2461,6 +2471,20 @@ mod verify {
                 let n: u32 = kani::any();
                 let result = x.rotate_left(n).rotate_right(n);
                 assert!(result == x);
+
+                // Rotating matches the underlying integer and never yields zero
+                let left = x.rotate_left(n);
+                let right = x.rotate_right(n);
+                assert!(left.get() == x.get().rotate_left(n));
+                assert!(right.get() == x.get().rotate_right(n));
+                assert!(left.get() != 0 && right.get() != 0);
+
+                // A single set bit moves in and out of the top (sign) position
+                let one = <$nonzero_type>::new(1).unwrap();
+                let top = <$nonzero_type>::new(1 << (<$nonzero_type>::BITS - 1)).unwrap();
+                assert!(one.rotate_right(1) == top);
+                assert!(top.rotate_left(1) == one);
+                assert!(one.rotate_left(n).get().count_ones() == 1);
             }
         };
     }
@@ -2478,4 +2502,147 @@ mod verify {
     nonzero_check_rotate_left_and_right!(core::num::NonZeroU64, nonzero_check_rotate_for_u64);
     nonzero_check_rotate_left_and_right!(core::num::NonZeroU128, nonzero_check_rotate_for_u128);
     nonzero_check_rotate_left_and_right!(core::num::NonZeroUsize, nonzero_check_rotate_for_usize);
+
+    // The harnesses in this file (and in `convert/num.rs`) draw their inputs
+    // from the `kani::Arbitrary` impl for `NonZero`, which Kani provides. Check
+    // that it never produces zero and that it does not rule out the values
+    // next to zero or at either end of the range, which an off-by-one in the
+    // impl would silently drop from every harness above.
+    macro_rules! nonzero_check_arbitrary {
+        ($t:ty, $nonzero_check_arbitrary_for:ident) => {
+            #[kani::proof]
+            pub fn $nonzero_check_arbitrary_for() {
+                let x: NonZero<$t> = kani::any();
+                assert!(x.get() != 0);
+
+                kani::cover!(x.get() == 1);
+                kani::cover!(x.get() == <$t>::MAX);
+                kani::cover!(x.get() == <$t>::MAX - 1);
+                if <$t>::MIN != 0 {
+                    // -1, below zero
+                    kani::cover!(x.get() == (0 as $t).wrapping_sub(1));
+                    kani::cover!(x.get() == <$t>::MIN);
+                    kani::cover!(x.get() == <$t>::MIN.wrapping_add(1));
+                }
+
+                // Any nonzero value at all can be produced.
+                let v: $t = kani::any_where(|v: &$t| *v != 0);
+                kani::cover!(x.get() == v);
+            }
+        };
+    }
+
+    nonzero_check_arbitrary!(i8, nonzero_check_arbitrary_for_i8);
+    nonzero_check_arbitrary!(i16, nonzero_check_arbitrary_for_i16);
+    nonzero_check_arbitrary!(i32, nonzero_check_arbitrary_for_i32);
+    nonzero_check_arbitrary!(i64, nonzero_check_arbitrary_for_i64);
+    nonzero_check_arbitrary!(i128, nonzero_check_arbitrary_for_i128);
+    nonzero_check_arbitrary!(isize, nonzero_check_arbitrary_for_isize);
+    nonzero_check_arbitrary!(u8, nonzero_check_arbitrary_for_u8);
+    nonzero_check_arbitrary!(u16, nonzero_check_arbitrary_for_u16);
+    nonzero_check_arbitrary!(u32, nonzero_check_arbitrary_for_u32);
+    nonzero_check_arbitrary!(u64, nonzero_check_arbitrary_for_u64);
+    nonzero_check_arbitrary!(u128, nonzero_check_arbitrary_for_u128);
+    nonzero_check_arbitrary!(usize, nonzero_check_arbitrary_for_usize);
+
+    macro_rules! nonzero_check_bitor {
+        ($t:ty, $nonzero_check_bitor_for:ident) => {
+            #[kani::proof]
+            pub fn $nonzero_check_bitor_for() {
+                let a: NonZero<$t> = kani::any();
+                let b: NonZero<$t> = kani::any();
+                let c: $t = kani::any();
+
+                // `NonZero | NonZero`
+                let result = a | b;
+                assert!(result.get() == a.get() | b.get());
+                assert!(result.get() != 0);
+
+                // `NonZero | T` and `T | NonZero`, which stay non-zero even if `c == 0`
+                let result = a | c;
+                assert!(result.get() == a.get() | c);
+                assert!(result.get() != 0);
+                let result = c | a;
+                assert!(result.get() == c | a.get());
+                assert!(result.get() != 0);
+                assert!((a | 0).get() == a.get());
+
+                // The `|=` forms agree with the above
+                let mut d = a;
+                d |= b;
+                assert!(d == a | b);
+                let mut d = a;
+                d |= c;
+                assert!(d == a | c);
+            }
+        };
+    }
+
+    // Use the macro to generate different versions of the function for multiple types
+    nonzero_check_bitor!(i8, nonzero_check_bitor_for_i8);
+    nonzero_check_bitor!(i16, nonzero_check_bitor_for_i16);
+    nonzero_check_bitor!(i32, nonzero_check_bitor_for_i32);
+    nonzero_check_bitor!(i64, nonzero_check_bitor_for_i64);
+    nonzero_check_bitor!(i128, nonzero_check_bitor_for_i128);
+    nonzero_check_bitor!(isize, nonzero_check_bitor_for_isize);
+    nonzero_check_bitor!(u8, nonzero_check_bitor_for_u8);
+    nonzero_check_bitor!(u16, nonzero_check_bitor_for_u16);
+    nonzero_check_bitor!(u32, nonzero_check_bitor_for_u32);
+    nonzero_check_bitor!(u64, nonzero_check_bitor_for_u64);
+    nonzero_check_bitor!(u128, nonzero_check_bitor_for_u128);
+    nonzero_check_bitor!(usize, nonzero_check_bitor_for_usize);
+
+    macro_rules! nonzero_check_pow {
+        ($t:ty, $nonzero_check_pow_for:ident) => {
+            #[kani::proof]
+            #[kani::unwind(9)]
+            pub fn $nonzero_check_pow_for() {
+                let x: NonZero<$t> = kani::any();
+                // Bounded so that the reference loop below stays small
+                let exp: u32 = kani::any_where(|e: &u32| *e <= 7);
+
+                // Reference: repeated multiplication, failing on overflow
+                let mut expected: Option<$t> = Some(1);
+                for _ in 0..exp {
+                    expected = expected.and_then(|acc| acc.checked_mul(x.get()));
+                }
+
+                let checked = x.checked_pow(exp);
+                assert!(checked.map(NonZero::get) == expected);
+
+                let saturated = x.saturating_pow(exp);
+                assert!(saturated.get() != 0);
+                match expected {
+                    Some(v) => assert!(saturated.get() == v),
+                    // Only a negative base raised to an odd power saturates downwards
+                    None => {
+                        let negative = <$t>::MIN != 0 && x.get().leading_zeros() == 0;
+                        if negative && exp % 2 == 1 {
+                            assert!(saturated.get() == <$t>::MIN);
+                        } else {
+                            assert!(saturated.get() == <$t>::MAX);
+                        }
+                    }
+                }
+
+                // `x^0` is one
+                assert!(x.checked_pow(0) == NonZero::new(1));
+                assert!(x.saturating_pow(0) == NonZero::new(1).unwrap());
+            }
+        };
+    }
+
+    // Use the macro to generate different versions of the function for multiple types
+    nonzero_check_pow!(i8, nonzero_check_pow_for_i8);
+    nonzero_check_pow!(i16, nonzero_check_pow_for_i16);
+    nonzero_check_pow!(i32, nonzero_check_pow_for_i32);
+    nonzero_check_pow!(i64, nonzero_check_pow_for_i64);
+    nonzero_check_pow!(i128, nonzero_check_pow_for_i128);
+    nonzero_check_pow!(isize, nonzero_check_pow_for_isize);
+    nonzero_check_pow!(u8, nonzero_check_pow_for_u8);
+    nonzero_check_pow!(u16, nonzero_check_pow_for_u16);
+    nonzero_check_pow!(u32, nonzero_check_pow_for_u32);
+    nonzero_check_pow!(u64, nonzero_check_pow_for_u64);
+    nonzero_check_pow!(u128, nonzero_check_pow_for_u128);
+    nonzero_check_pow!(usize, nonzero_check_pow_for_usize);
 }
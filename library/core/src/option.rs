@@ -556,6 +556,8 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use safety::requires;
+
 use crate::iter::{self, FusedIterator, TrustedLen};
 use crate::ops::{self, ControlFlow, Deref, DerefMut};
 use crate::panicking::{panic, panic_display};
@@ -1074,6 +1076,7 @@ impl<T> Option<T> {
     #[stable(feature = "option_result_unwrap_unchecked", since = "1.58.0")]
     #[rustc_allow_const_fn_unstable(const_precise_live_drops)]
     #[rustc_const_stable(feature = "const_option", since = "1.83.0")]
+    #[requires(self.is_some())]
     pub const unsafe fn unwrap_unchecked(self) -> T {
         match self {
             Some(val) => val,
@@ -2598,4 +2601,35 @@ mod verify {
             assert!(empty_slice.is_empty()); // Explicit check for emptiness
         }
     }
+
+    // pub const unsafe fn unwrap_unchecked(self) -> T
+    #[kani::proof_for_contract(Option::<u32>::unwrap_unchecked)]
+    fn verify_unwrap_unchecked() {
+        let option: Option<u32> = kani::any();
+        let expected = option;
+        let value = unsafe { option.unwrap_unchecked() };
+        assert_eq!(Some(value), expected);
+    }
+
+    // pub fn map<U, F>(self, f: F) -> Option<U>
+    // pub fn and_then<U, F>(self, f: F) -> Option<U>
+    #[kani::proof]
+    fn verify_map_and_then() {
+        let f = |x: u32| x.wrapping_mul(3) as u64;
+        let g = |x: u32| x.checked_sub(7);
+
+        assert_eq!(None::<u32>.map(f), None);
+        assert_eq!(None::<u32>.and_then(g), None);
+
+        let x: u32 = kani::any();
+        assert_eq!(Some(x).map(f), Some(f(x)));
+        assert_eq!(Some(x).and_then(g), g(x));
+        assert_eq!(Some(x).and_then(|_| None::<u32>), None);
+        // `Some` is the unit of `and_then`
+        assert_eq!(Some(x).and_then(Some), Some(x));
+
+        let option: Option<u32> = kani::any();
+        assert_eq!(option.map(f).is_some(), option.is_some());
+        assert_eq!(option.and_then(|x| Some(f(x))), option.map(f));
+    }
 }
@@ -490,6 +490,8 @@ impl<T: ?Sized> *const T {
         (self.addr() == result.addr()) ||
         (core::ub_checks::same_allocation(self, result))
     )]
+    // The offset is applied in bytes, regardless of the size of `T`
+    #[ensures(|&result| result.addr() == self.addr().wrapping_add_signed(count))]
     pub const unsafe fn byte_offset(self, count: isize) -> Self {
         // SAFETY: the caller must uphold the safety contract for `offset`.
         unsafe { self.cast::<u8>().offset(count).with_metadata_of(self) }
@@ -1052,6 +1054,7 @@ impl<T: ?Sized> *const T {
         (self.addr() == result.addr()) ||
         (core::ub_checks::same_allocation(self, result))
     )]
+    #[ensures(|&result| result.addr() == self.addr().wrapping_add(count))]
     pub const unsafe fn byte_add(self, count: usize) -> Self {
         // SAFETY: the caller must uphold the safety contract for `add`.
         unsafe { self.cast::<u8>().add(count).with_metadata_of(self) }
@@ -1196,6 +1199,7 @@ impl<T: ?Sized> *const T {
         (self.addr() == result.addr()) ||
         (core::ub_checks::same_allocation(self, result))
     )]
+    #[ensures(|&result| result.addr() == self.addr().wrapping_sub(count))]
     pub const unsafe fn byte_sub(self, count: usize) -> Self {
         // SAFETY: the caller must uphold the safety contract for `sub`.
         unsafe { self.cast::<u8>().sub(count).with_metadata_of(self) }
@@ -1575,6 +1579,8 @@ impl<T: ?Sized> *const T {
     #[must_use]
     #[inline]
     #[unstable(feature = "pointer_is_aligned_to", issue = "96284")]
+    #[requires(align.is_power_of_two())]
+    #[ensures(|result| *result == (self.addr() % align == 0))]
     pub fn is_aligned_to(self, align: usize) -> bool {
         if !align.is_power_of_two() {
             panic!("is_aligned_to: align is not a power-of-two");
@@ -2785,4 +2791,197 @@ mod verify {
             ptr_caller.byte_offset_from(ptr_input);
         }
     }
+
+    // Checks `<*const T>::align_offset` for a pointee whose stride is zero or a
+    // power of two. In that case aligning `p` to `align` is possible exactly
+    // when `p` is already aligned to `min(stride, align)` (to `align` for
+    // ZSTs, which cannot move at all).
+    fn check_align_offset_pow2_stride<T>() {
+        let p = kani::any::<usize>() as *const T;
+        let align = kani::any_where(|align: &usize| align.is_power_of_two());
+        let stride = mem::size_of::<T>();
+
+        let ret = p.align_offset(align);
+
+        let possible = if stride == 0 {
+            p.addr() % align == 0
+        } else {
+            p.addr() % crate::cmp::min(stride, align) == 0
+        };
+        assert_eq!(ret != usize::MAX, possible);
+        if ret != usize::MAX {
+            assert!(p.wrapping_add(ret).is_aligned_to(align));
+        }
+    }
+
+    // pub fn align_offset(self, align: usize) -> usize where T: Sized
+    #[kani::proof]
+    fn check_const_align_offset_unit() {
+        check_align_offset_pow2_stride::<()>();
+    }
+
+    #[kani::proof]
+    fn check_const_align_offset_u8() {
+        check_align_offset_pow2_stride::<u8>();
+    }
+
+    #[kani::proof]
+    fn check_const_align_offset_u16() {
+        check_align_offset_pow2_stride::<u16>();
+    }
+
+    #[kani::proof]
+    fn check_const_align_offset_u32() {
+        check_align_offset_pow2_stride::<u32>();
+    }
+
+    #[kani::proof]
+    fn check_const_align_offset_u64() {
+        check_align_offset_pow2_stride::<u64>();
+    }
+
+    #[kani::proof]
+    fn check_const_align_offset_u128() {
+        check_align_offset_pow2_stride::<u128>();
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_const_align_offset_not_power_of_two() {
+        let p = kani::any::<usize>() as *const u8;
+        let align = kani::any_where(|align: &usize| !align.is_power_of_two());
+        p.align_offset(align);
+    }
+
+    // pub fn is_aligned_to(self, align: usize) -> bool
+    #[kani::proof_for_contract(<*const u8>::is_aligned_to)]
+    fn check_const_is_aligned_to() {
+        let p = kani::any::<usize>() as *const u8;
+        let align = kani::any();
+        p.is_aligned_to(align);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_const_is_aligned_to_not_power_of_two() {
+        let p = kani::any::<usize>() as *const u8;
+        let align = kani::any_where(|align: &usize| !align.is_power_of_two());
+        p.is_aligned_to(align);
+    }
+
+    // pub fn is_aligned(self) -> bool where T: Sized
+    macro_rules! generate_const_is_aligned_harness {
+        ($type:ty, $proof_name:ident) => {
+            #[kani::proof]
+            pub fn $proof_name() {
+                let p = kani::any::<usize>() as *const $type;
+                let aligned = p.is_aligned();
+                assert_eq!(aligned, p.is_aligned_to(mem::align_of::<$type>()));
+                assert_eq!(aligned, p.addr() % mem::align_of::<$type>() == 0);
+            }
+        };
+    }
+
+    // ZSTs with an alignment of 1 are aligned at every address.
+    generate_const_is_aligned_harness!((), check_const_is_aligned_unit);
+    generate_const_is_aligned_harness!(u8, check_const_is_aligned_u8);
+    generate_const_is_aligned_harness!(u16, check_const_is_aligned_u16);
+    generate_const_is_aligned_harness!(u32, check_const_is_aligned_u32);
+    generate_const_is_aligned_harness!(u64, check_const_is_aligned_u64);
+    generate_const_is_aligned_harness!(u128, check_const_is_aligned_u128);
+    generate_const_is_aligned_harness!([u32; 0], check_const_is_aligned_zst_u32);
+
+    #[kani::proof]
+    fn check_const_is_aligned_unit_always() {
+        let p = kani::any::<usize>() as *const ();
+        assert!(p.is_aligned());
+    }
+
+    // Checks that moving by `size_of::<T>()` bytes is the same as moving by one
+    // element, for pointee types whose size is not 1.
+    // - `$type`: pointee type
+    // - `$proof_name`: name of the harness generated
+    macro_rules! gen_const_byte_arith_matches_element_harness {
+        ($type:ty, $proof_name:ident) => {
+            #[kani::proof]
+            pub fn $proof_name() {
+                const LEN: usize = 4;
+                let arr: [$type; LEN] = kani::any();
+                let size = mem::size_of::<$type>();
+                // Any element, or one past the end.
+                let idx = kani::any_where(|idx: &usize| *idx <= LEN);
+                let ptr: *const $type = arr.as_ptr().wrapping_add(idx);
+
+                unsafe {
+                    if idx < LEN {
+                        assert_eq!(ptr.byte_add(size), ptr.add(1));
+                        assert_eq!(ptr.byte_offset(size as isize), ptr.offset(1));
+                    }
+                    if idx > 0 {
+                        assert_eq!(ptr.byte_sub(size), ptr.sub(1));
+                        assert_eq!(ptr.byte_offset(-(size as isize)), ptr.offset(-1));
+                    }
+                }
+            }
+        };
+    }
+
+    gen_const_byte_arith_matches_element_harness!(u16, check_const_byte_arith_matches_element_u16);
+    gen_const_byte_arith_matches_element_harness!(u32, check_const_byte_arith_matches_element_u32);
+    gen_const_byte_arith_matches_element_harness!(u64, check_const_byte_arith_matches_element_u64);
+    gen_const_byte_arith_matches_element_harness!(
+        (u8, u16),
+        check_const_byte_arith_matches_element_tuple
+    );
+    gen_const_byte_arith_matches_element_harness!(
+        [u8; 3],
+        check_const_byte_arith_matches_element_array
+    );
+
+    // `wrapping_add` and `wrapping_sub` have no safety requirements: for any
+    // address and any count, including ones that overflow the address space,
+    // they just wrap the address.
+    // - `$type`: pointee type
+    // - `$add_name`, `$sub_name`: names of the harnesses generated
+    macro_rules! gen_const_wrapping_arith_harness {
+        ($type:ty, $add_name:ident, $sub_name:ident) => {
+            #[kani::proof]
+            pub fn $add_name() {
+                let ptr = kani::any::<usize>() as *const $type;
+                let count: usize = kani::any();
+                let bytes = count.wrapping_mul(mem::size_of::<$type>());
+                assert_eq!(ptr.wrapping_add(count).addr(), ptr.addr().wrapping_add(bytes));
+            }
+
+            #[kani::proof]
+            pub fn $sub_name() {
+                let ptr = kani::any::<usize>() as *const $type;
+                let count: usize = kani::any();
+                let bytes = count.wrapping_mul(mem::size_of::<$type>());
+                assert_eq!(ptr.wrapping_sub(count).addr(), ptr.addr().wrapping_sub(bytes));
+            }
+        };
+    }
+
+    gen_const_wrapping_arith_harness!(
+        (),
+        check_const_wrapping_add_unit,
+        check_const_wrapping_sub_unit
+    );
+    gen_const_wrapping_arith_harness!(u8, check_const_wrapping_add_u8, check_const_wrapping_sub_u8);
+    gen_const_wrapping_arith_harness!(
+        u32,
+        check_const_wrapping_add_u32,
+        check_const_wrapping_sub_u32
+    );
+    gen_const_wrapping_arith_harness!(
+        (u8, u16),
+        check_const_wrapping_add_tuple,
+        check_const_wrapping_sub_tuple
+    );
+    gen_const_wrapping_arith_harness!(
+        [u8; 3],
+        check_const_wrapping_add_array,
+        check_const_wrapping_sub_array
+    );
 }
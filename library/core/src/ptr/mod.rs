@@ -1073,6 +1073,13 @@ pub const unsafe fn swap<T>(x: *mut T, y: *mut T) {
 #[stable(feature = "swap_nonoverlapping", since = "1.27.0")]
 #[rustc_const_unstable(feature = "const_swap", issue = "83163")]
 #[rustc_diagnostic_item = "ptr_swap_nonoverlapping"]
+#[safety::requires(
+    ub_checks::can_dereference(slice_from_raw_parts(x, count))
+        && ub_checks::can_write(slice_from_raw_parts_mut(x, count))
+        && ub_checks::can_dereference(slice_from_raw_parts(y, count))
+        && ub_checks::can_write(slice_from_raw_parts_mut(y, count))
+        && ub_checks::is_nonoverlapping(x as *const (), y as *const (), size_of::<T>(), count)
+)]
 pub const unsafe fn swap_nonoverlapping<T>(x: *mut T, y: *mut T, count: usize) {
     #[allow(unused)]
     macro_rules! attempt_swap_as_chunks {
@@ -1205,6 +1212,7 @@ const unsafe fn swap_nonoverlapping_simple_untyped<T>(x: *mut T, y: *mut T, coun
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_const_stable(feature = "const_replace", since = "1.83.0")]
 #[rustc_diagnostic_item = "ptr_replace"]
+#[safety::requires(ub_checks::can_dereference(dst) && ub_checks::can_write(dst))]
 pub const unsafe fn replace<T>(dst: *mut T, src: T) -> T {
     // SAFETY: the caller must guarantee that `dst` is valid to be
     // cast to a mutable reference (valid for writes, aligned, initialized),
@@ -2448,6 +2456,7 @@ mod verify {
     use super::*;
     use crate::fmt::Debug;
     use crate::kani;
+    use crate::verify_support::DropCounter;
 
     #[kani::proof_for_contract(read_volatile)]
     pub fn check_read_u128() {
@@ -2576,4 +2585,166 @@ mod verify {
         let m = kani::any::<usize>();
         unsafe { mod_inv_copy(x, m) };
     }
+
+    #[kani::proof_for_contract(write_volatile)]
+    pub fn check_write_volatile_u32() {
+        let mut val = kani::any::<u32>();
+        let new_val = kani::any::<u32>();
+        unsafe { write_volatile(&mut val as *mut _, new_val) };
+        assert_eq!(val, new_val);
+    }
+
+    // A volatile store followed by a volatile load from the same place must
+    // observe the stored value.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    pub fn check_volatile_round_trip() {
+        let mut buf = kani::any::<[u32; 4]>();
+        let original = buf;
+        let idx = kani::any_where(|idx: &usize| *idx < buf.len());
+        let ptr = buf.as_mut_ptr().wrapping_add(idx);
+        let new_val = kani::any::<u32>();
+        unsafe {
+            write_volatile(ptr, new_val);
+            assert_eq!(read_volatile(ptr), new_val);
+        }
+        assert_eq!(buf[idx], new_val);
+        // The write touches only the addressed slot.
+        for j in 0..buf.len() {
+            if j != idx {
+                assert_eq!(buf[j], original[j]);
+            }
+        }
+    }
+
+    // pub const unsafe fn replace<T>(dst: *mut T, src: T) -> T
+    #[kani::proof_for_contract(replace)]
+    pub fn check_replace_u32() {
+        let old_val = kani::any::<u32>();
+        let new_val = kani::any::<u32>();
+        let mut val = old_val;
+        let ret = unsafe { replace(&mut val as *mut _, new_val) };
+        assert_eq!(ret, old_val);
+        assert_eq!(val, new_val);
+    }
+
+    // pub const unsafe fn swap_nonoverlapping<T>(x: *mut T, y: *mut T, count: usize)
+    #[kani::proof_for_contract(swap_nonoverlapping)]
+    #[kani::unwind(5)]
+    pub fn check_swap_nonoverlapping() {
+        const ARR_SIZE: usize = 4;
+        let mut buf: [u32; ARR_SIZE] = kani::any();
+        let original = buf;
+        let x_start = kani::any_where(|i: &usize| *i <= ARR_SIZE);
+        let y_start = kani::any_where(|i: &usize| *i <= ARR_SIZE);
+        // `count` is left unconstrained; the contract rejects regions that
+        // run out of `buf` or overlap, including when `count * 4` overflows.
+        let count: usize = kani::any();
+        let base = buf.as_mut_ptr();
+        let x = base.wrapping_add(x_start);
+        let y = base.wrapping_add(y_start);
+
+        unsafe { swap_nonoverlapping(x, y, count) };
+        for i in 0..count {
+            assert_eq!(buf[x_start + i], original[y_start + i]);
+            assert_eq!(buf[y_start + i], original[x_start + i]);
+        }
+    }
+
+    // The old value must be moved out to the caller, not dropped in place,
+    // and the new one must not be dropped either.
+    #[kani::proof]
+    pub fn check_replace_does_not_drop() {
+        let drops = crate::cell::Cell::new(0);
+        let old_id = kani::any::<u8>();
+        let new_id = kani::any::<u8>();
+        let mut val = DropCounter::new(old_id, &drops);
+        let ret = unsafe { replace(&mut val as *mut _, DropCounter::new(new_id, &drops)) };
+        assert_eq!(drops.get(), 0);
+        assert_eq!(ret.value, old_id);
+        assert_eq!(val.value, new_id);
+        drop(ret);
+        assert_eq!(drops.get(), 1);
+        drop(val);
+        assert_eq!(drops.get(), 2);
+    }
+
+    // Records the order in which `Logged` values are dropped.
+    struct DropLog {
+        entries: crate::cell::Cell<[u8; 8]>,
+        len: crate::cell::Cell<usize>,
+    }
+
+    impl DropLog {
+        fn push(&self, id: u8) {
+            let mut entries = self.entries.get();
+            entries[self.len.get()] = id;
+            self.entries.set(entries);
+            self.len.set(self.len.get() + 1);
+        }
+    }
+
+    struct Logged<'a>(u8, &'a DropLog);
+
+    impl Drop for Logged<'_> {
+        fn drop(&mut self) {
+            self.1.push(self.0);
+        }
+    }
+
+    struct Inner<'a> {
+        this: Logged<'a>,
+        b: Logged<'a>,
+        c: Option<Logged<'a>>,
+    }
+
+    impl Drop for Inner<'_> {
+        fn drop(&mut self) {
+            self.this.1.push(10);
+        }
+    }
+
+    struct Outer<'a> {
+        a: Logged<'a>,
+        inner: Inner<'a>,
+        d: Logged<'a>,
+    }
+
+    impl Drop for Outer<'_> {
+        fn drop(&mut self) {
+            self.a.1.push(0);
+        }
+    }
+
+    // pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T)
+    //
+    // The outer `Drop` impl runs first, then the fields in declaration order,
+    // recursively, each exactly once.
+    #[kani::proof]
+    #[kani::unwind(9)]
+    pub fn check_drop_in_place_nested() {
+        let log =
+            DropLog { entries: crate::cell::Cell::new([0; 8]), len: crate::cell::Cell::new(0) };
+        let has_c: bool = kani::any();
+        let mut value = mem::ManuallyDrop::new(Outer {
+            a: Logged(1, &log),
+            inner: Inner {
+                this: Logged(2, &log),
+                b: Logged(3, &log),
+                c: if has_c { Some(Logged(4, &log)) } else { None },
+            },
+            d: Logged(5, &log),
+        });
+
+        unsafe { drop_in_place(&mut *value as *mut Outer<'_>) };
+
+        let entries = log.entries.get();
+        if has_c {
+            assert_eq!(log.len.get(), 7);
+            assert_eq!(entries[..7], [0, 1, 10, 2, 3, 4, 5]);
+        } else {
+            assert_eq!(log.len.get(), 6);
+            assert_eq!(entries[..6], [0, 1, 10, 2, 3, 5]);
+        }
+    }
 }
@@ -391,7 +391,9 @@ impl<T: ?Sized> NonNull<T> {
     #[rustc_const_stable(feature = "const_nonnull_as_ref", since = "1.73.0")]
     #[must_use]
     #[inline(always)]
-    #[requires(ub_checks::can_dereference(self.as_ptr() as *const()))] // Ensure input is convertible to a reference
+    // Ensure input is convertible to a reference; checking through `*const T` rather than
+    // `*const ()` also covers the size and alignment of `T`.
+    #[requires(ub_checks::can_dereference(self.as_ptr().cast_const()))]
     #[ensures(|result: &&T| core::ptr::eq(*result, self.as_ptr()))] // Ensure returned reference matches pointer
     pub const unsafe fn as_ref<'a>(&self) -> &'a T {
         // SAFETY: the caller must guarantee that `self` meets all the
@@ -431,7 +433,8 @@ impl<T: ?Sized> NonNull<T> {
     #[rustc_const_stable(feature = "const_ptr_as_ref", since = "1.83.0")]
     #[must_use]
     #[inline(always)]
-    #[requires(ub_checks::can_dereference(self.as_ptr() as *const()))]
+    #[requires(ub_checks::can_dereference(self.as_ptr().cast_const()))]
+    #[requires(ub_checks::can_write(self.as_ptr()))]
     // verify result (a mutable reference) is still associated with the same memory address as the raw pointer stored in self
     #[ensures(|result: &&mut T| core::ptr::eq(*result, self.as_ptr()))]
     pub const unsafe fn as_mut<'a>(&mut self) -> &'a mut T {
@@ -1067,7 +1070,7 @@ impl<T: ?Sized> NonNull<T> {
     #[requires(count.checked_mul(core::mem::size_of::<T>()).map_or_else(|| false, |size| size <= isize::MAX as usize)
         && ub_checks::can_dereference(NonNull::slice_from_raw_parts(self, count).as_ptr())
         && ub_checks::can_write(NonNull::slice_from_raw_parts(dest, count).as_ptr())
-        && ub_checks::maybe_is_nonoverlapping(self.as_ptr() as *const (), dest.as_ptr() as *const (), count, core::mem::size_of::<T>()))]
+        && ub_checks::is_nonoverlapping(self.as_ptr() as *const (), dest.as_ptr() as *const (), core::mem::size_of::<T>(), count))]
     #[ensures(|result: &()| ub_checks::can_dereference(self.as_ptr() as *const u8)
         && ub_checks::can_dereference(dest.as_ptr() as *const u8))]
     pub const unsafe fn copy_to_nonoverlapping(self, dest: NonNull<T>, count: usize)
@@ -1120,7 +1123,7 @@ impl<T: ?Sized> NonNull<T> {
     #[requires(count.checked_mul(core::mem::size_of::<T>()).map_or_else(|| false, |size| size <= isize::MAX as usize)
         && ub_checks::can_dereference(NonNull::slice_from_raw_parts(src, count).as_ptr())
         && ub_checks::can_write(NonNull::slice_from_raw_parts(self, count).as_ptr())
-        && ub_checks::maybe_is_nonoverlapping(src.as_ptr() as *const (), self.as_ptr() as *const (), count, core::mem::size_of::<T>()))]
+        && ub_checks::is_nonoverlapping(src.as_ptr() as *const (), self.as_ptr() as *const (), core::mem::size_of::<T>(), count))]
     #[ensures(|result: &()| ub_checks::can_dereference(src.as_ptr() as *const u8)
         && ub_checks::can_dereference(self.as_ptr() as *const u8))]
     pub const unsafe fn copy_from_nonoverlapping(self, src: NonNull<T>, count: usize)
@@ -2647,4 +2650,44 @@ mod verify {
             src.copy_from_nonoverlapping(dest, count);
         }
     }
+
+    #[kani::proof_for_contract(NonNull::as_ref)]
+    pub fn non_null_check_as_ref_observes_write() {
+        let mut x: u64 = kani::any();
+        let new_val: u64 = kani::any();
+        let ptr = NonNull::from(&mut x);
+        unsafe {
+            ptr.as_ptr().write(new_val);
+            assert_eq!(*ptr.as_ref(), new_val);
+        }
+    }
+
+    #[kani::proof_for_contract(NonNull::as_mut)]
+    pub fn non_null_check_as_mut_write_through() {
+        let mut x: u64 = kani::any();
+        let new_val: u64 = kani::any();
+        let mut ptr = NonNull::from(&mut x);
+        unsafe {
+            *ptr.as_mut() = new_val;
+            assert_eq!(*ptr.as_ref(), new_val);
+        }
+        assert_eq!(x, new_val);
+    }
+
+    // A dangling but aligned pointer is valid for ZSTs.
+    #[kani::proof_for_contract(NonNull::as_ref)]
+    pub fn non_null_check_as_ref_zst_dangling() {
+        let ptr = NonNull::<[u32; 0]>::dangling();
+        unsafe {
+            let _: &[u32; 0] = ptr.as_ref();
+        }
+    }
+
+    #[kani::proof_for_contract(NonNull::as_mut)]
+    pub fn non_null_check_as_mut_zst_dangling() {
+        let mut ptr = NonNull::<()>::dangling();
+        unsafe {
+            *ptr.as_mut() = ();
+        }
+    }
 }
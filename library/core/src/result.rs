@@ -513,6 +513,8 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use safety::requires;
+
 use crate::iter::{self, FusedIterator, TrustedLen};
 use crate::ops::{self, ControlFlow, Deref, DerefMut};
 use crate::{convert, fmt, hint};
@@ -1482,6 +1484,7 @@ impl<T, E> Result<T, E> {
     #[inline]
     #[track_caller]
     #[stable(feature = "option_result_unwrap_unchecked", since = "1.58.0")]
+    #[requires(self.is_ok())]
     pub unsafe fn unwrap_unchecked(self) -> T {
         match self {
             Ok(t) => t,
@@ -1513,6 +1516,7 @@ impl<T, E> Result<T, E> {
     #[inline]
     #[track_caller]
     #[stable(feature = "option_result_unwrap_unchecked", since = "1.58.0")]
+    #[requires(self.is_err())]
     pub unsafe fn unwrap_err_unchecked(self) -> E {
         match self {
             // SAFETY: the safety contract must be upheld by the caller.
@@ -2023,3 +2027,50 @@ impl<T, E, F: From<E>> ops::FromResidual<ops::Yeet<E>> for Result<T, F> {
 impl<T, E> ops::Residual<T> for Result<convert::Infallible, E> {
     type TryType = Result<T, E>;
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use crate::kani;
+    use crate::result::Result;
+
+    fn any_result() -> Result<u32, i8> {
+        if kani::any() {
+            Ok(kani::any())
+        } else {
+            Err(kani::any())
+        }
+    }
+
+    // pub unsafe fn unwrap_unchecked(self) -> T
+    #[kani::proof_for_contract(Result::<u32, i8>::unwrap_unchecked)]
+    fn verify_unwrap_unchecked() {
+        let result = any_result();
+        let expected = result;
+        let value = unsafe { result.unwrap_unchecked() };
+        assert_eq!(Ok(value), expected);
+    }
+
+    // pub unsafe fn unwrap_err_unchecked(self) -> E
+    #[kani::proof_for_contract(Result::<u32, i8>::unwrap_err_unchecked)]
+    fn verify_unwrap_err_unchecked() {
+        let result = any_result();
+        let expected = result;
+        let error = unsafe { result.unwrap_err_unchecked() };
+        assert_eq!(Err(error), expected);
+    }
+
+    // pub fn ok(self) -> Option<T>
+    // pub fn err(self) -> Option<E>
+    #[kani::proof]
+    fn verify_ok_err() {
+        let result = any_result();
+        let (ok, err) = (result.ok(), result.err());
+        // Exactly one side is `Some`
+        assert!(ok.is_some() != err.is_some());
+        match result {
+            Ok(v) => assert!(ok == Some(v) && err.is_none()),
+            Err(e) => assert!(err == Some(e) && ok.is_none()),
+        }
+    }
+}
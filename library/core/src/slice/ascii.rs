@@ -485,4 +485,52 @@ pub mod verify {
             }
         }
     }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    pub fn check_eq_ignore_ascii_case() {
+        // Reference lowercase mapping which only touches `A..=Z`.
+        fn lower(b: u8) -> u8 {
+            if b >= b'A' && b <= b'Z' { b + (b'a' - b'A') } else { b }
+        }
+
+        const ARR_SIZE: usize = 4;
+        let x: [u8; ARR_SIZE] = kani::any();
+        let y: [u8; ARR_SIZE] = kani::any();
+        let xs = kani::slice::any_slice_of_array(&x);
+        let ys = kani::slice::any_slice_of_array(&y);
+
+        let expected =
+            xs.len() == ys.len() && xs.iter().zip(ys).all(|(a, b)| lower(*a) == lower(*b));
+        assert_eq!(xs.eq_ignore_ascii_case(ys), expected);
+
+        // `[` is `Z` + 1, so it must not match any letter.
+        assert!(b"A".eq_ignore_ascii_case(b"a"));
+        assert!(!b"[".eq_ignore_ascii_case(b"{"));
+        assert!(!b"a".eq_ignore_ascii_case(b"["));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    pub fn check_make_ascii_case() {
+        const ARR_SIZE: usize = 4;
+        let original: [u8; ARR_SIZE] = kani::any();
+
+        let len = kani::any_where(|len: &usize| *len <= ARR_SIZE);
+
+        let mut upper = original;
+        upper[..len].make_ascii_uppercase();
+        let mut lower = original;
+        lower[..len].make_ascii_lowercase();
+
+        for i in 0..ARR_SIZE {
+            if i < len {
+                assert_eq!(upper[i], original[i].to_ascii_uppercase());
+                assert_eq!(lower[i], original[i].to_ascii_lowercase());
+            } else {
+                assert_eq!(upper[i], original[i]);
+                assert_eq!(lower[i], original[i]);
+            }
+        }
+    }
 }
@@ -3657,4 +3657,104 @@ mod verify {
     check_iter_with_ty!(verify_u8, u8, u32::MAX as usize);
     check_iter_with_ty!(verify_char, char, 50);
     check_iter_with_ty!(verify_tup, (char, u8), 50);
+
+    // fn sum<S>(self) -> S, over a slice of signed integers
+    //
+    // Summation adds from the left with overflow checks, so it succeeds
+    // exactly when every partial sum fits, and then equals the reference fold.
+    macro_rules! check_sum {
+        ($harness:ident, $overflow_harness:ident, $ty:ty) => {
+            #[kani::proof]
+            #[kani::unwind(5)]
+            fn $harness() {
+                let array: [$ty; 4] = kani::any();
+                let slice = kani::slice::any_slice_of_array(&array);
+                let mut expected: Option<$ty> = Some(0);
+                for x in slice {
+                    expected = expected.and_then(|acc| acc.checked_add(*x));
+                }
+                kani::assume(expected.is_some());
+
+                let sum = slice.iter().sum::<$ty>();
+                assert_eq!(Some(sum), expected);
+                kani::cover!(slice.is_empty() && sum == 0);
+                kani::cover!(slice.len() > 1 && sum == <$ty>::MAX);
+            }
+
+            #[kani::proof]
+            #[kani::unwind(5)]
+            #[kani::should_panic]
+            fn $overflow_harness() {
+                let array: [$ty; 4] = kani::any();
+                let slice = kani::slice::any_slice_of_array(&array);
+                let mut expected: Option<$ty> = Some(0);
+                for x in slice {
+                    expected = expected.and_then(|acc| acc.checked_add(*x));
+                }
+                kani::assume(expected.is_none());
+                let _ = slice.iter().sum::<$ty>();
+            }
+        };
+    }
+
+    check_sum!(check_sum_i8, check_sum_overflow_i8, i8);
+    check_sum!(check_sum_i32, check_sum_overflow_i32, i32);
+    check_sum!(check_sum_i64, check_sum_overflow_i64, i64);
+
+    // fn fold<B, F>(self, init: B, f: F) -> B
+    //
+    // Compared against `f` applied by hand, once for `+` and once for a
+    // non-commutative step that would expose a reordered or repeated element.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_fold() {
+        let array: [u8; 4] = kani::any();
+        let len = kani::any_where(|len: &usize| *len <= 4);
+        let slice = &array[..len];
+        let init: u8 = kani::any();
+
+        let add: fn(u8, &u8) -> u8 = |acc, x| acc.wrapping_add(*x);
+        let step: fn(u8, &u8) -> u8 = |acc, x| acc.wrapping_mul(3).wrapping_add(*x);
+        for f in [add, step] {
+            let mut expected = init;
+            if len > 0 {
+                expected = f(expected, &array[0]);
+            }
+            if len > 1 {
+                expected = f(expected, &array[1]);
+            }
+            if len > 2 {
+                expected = f(expected, &array[2]);
+            }
+            if len > 3 {
+                expected = f(expected, &array[3]);
+            }
+            assert_eq!(slice.iter().fold(init, f), expected);
+        }
+    }
+
+    // fn max(self) -> Option<Self::Item>
+    // fn min(self) -> Option<Self::Item>
+    //
+    // Besides being extremal, `max` must return the last of several equal
+    // maxima and `min` the first of several equal minima. The items are
+    // references into the slice, so identity is checked by address.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_max_min() {
+        let array: [u8; 4] = kani::any();
+        let slice = kani::slice::any_slice_of_array(&array);
+
+        match (slice.iter().max(), slice.iter().min()) {
+            (Some(max), Some(min)) => {
+                let max_idx = slice.iter().rposition(|x| x == max).unwrap();
+                let min_idx = slice.iter().position(|x| x == min).unwrap();
+                assert!(crate::ptr::eq(max, &slice[max_idx]));
+                assert!(crate::ptr::eq(min, &slice[min_idx]));
+                assert!(slice.iter().all(|x| min <= x && x <= max));
+            }
+            (max, min) => assert!(max.is_none() && min.is_none() && slice.is_empty()),
+        }
+        kani::cover!(slice.len() == 2 && slice[0] == slice[1]);
+    }
 }
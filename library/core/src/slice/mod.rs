@@ -6,6 +6,8 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use safety::{ensures, requires};
+
 use crate::cmp::Ordering::{self, Equal, Greater, Less};
 use crate::intrinsics::{exact_div, select_unpredictable, unchecked_sub};
 use crate::mem::{self, SizedTypeProperties};
@@ -3602,6 +3604,7 @@ impl<T> [T] {
     /// [`split_at_mut`]: slice::split_at_mut
     #[stable(feature = "clone_from_slice", since = "1.7.0")]
     #[track_caller]
+    #[requires(self.len() == src.len())]
     pub fn clone_from_slice(&mut self, src: &[T])
     where
         T: Clone,
@@ -3666,6 +3669,7 @@ impl<T> [T] {
     #[doc(alias = "memcpy")]
     #[stable(feature = "copy_from_slice", since = "1.9.0")]
     #[track_caller]
+    #[requires(self.len() == src.len())]
     pub fn copy_from_slice(&mut self, src: &[T])
     where
         T: Copy,
@@ -4495,6 +4499,15 @@ impl<T> [T] {
     /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
     #[unstable(feature = "get_many_mut", issue = "104642")]
     #[inline]
+    // Every index is in bounds and differs from all the indices before it.
+    #[requires(indices.iter().enumerate().all(|(i, &idx)| {
+        idx < self.len() && indices[..i].iter().all(|&prev| prev != idx)
+    }))]
+    // Each reference points at the element of `self` at the matching index.
+    #[ensures(|result| {
+        let base = old(self.as_ptr());
+        (0..N).all(|i| ptr::eq(&*result[i], base.wrapping_add(indices[i])))
+    })]
     pub unsafe fn get_many_unchecked_mut<const N: usize>(
         &mut self,
         indices: [usize; N],
@@ -4933,3 +4946,418 @@ impl<const N: usize> fmt::Display for GetManyMutError<N> {
         fmt::Display::fmt("an index is out of bounds or appeared multiple times in the array", f)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::kani;
+
+    // pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N])
+    //     -> Result<[&mut T; N], GetManyMutError<N>>
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_get_many_mut() {
+        const ARR_SIZE: usize = 4;
+        const N: usize = 3;
+        let mut arr: [u8; ARR_SIZE] = kani::any();
+        let original = arr;
+        let base: *const u8 = arr.as_ptr();
+        let indices: [usize; N] = kani::any();
+
+        let in_bounds = indices.iter().all(|&i| i < ARR_SIZE);
+        let distinct =
+            indices[0] != indices[1] && indices[0] != indices[2] && indices[1] != indices[2];
+
+        match arr[..].get_many_mut(indices) {
+            Ok(refs) => {
+                assert!(in_bounds && distinct);
+                for k in 0..N {
+                    assert!(ptr::eq(&*refs[k], base.wrapping_add(indices[k])));
+                    assert_eq!(*refs[k], original[indices[k]]);
+                }
+            }
+            Err(_) => assert!(!in_bounds || !distinct),
+        }
+    }
+
+    // pub unsafe fn get_many_unchecked_mut<const N: usize>(&mut self, indices: [usize; N])
+    //     -> [&mut T; N]
+    #[kani::proof_for_contract(<[u8]>::get_many_unchecked_mut)]
+    #[kani::unwind(5)]
+    fn check_get_many_unchecked_mut() {
+        const ARR_SIZE: usize = 4;
+        let mut arr: [u8; ARR_SIZE] = kani::any();
+        let original = arr;
+        let base: *const u8 = arr.as_ptr();
+        let indices: [usize; 3] = kani::any();
+
+        let refs = unsafe { arr[..].get_many_unchecked_mut(indices) };
+        for k in 0..3 {
+            assert!(ptr::eq(&*refs[k], base.wrapping_add(indices[k])));
+            assert_eq!(*refs[k], original[indices[k]]);
+        }
+    }
+
+    // pub fn reverse(&mut self)
+    #[kani::proof]
+    #[kani::unwind(8)]
+    fn check_reverse() {
+        const ARR_SIZE: usize = 5;
+        let mut arr: [u8; ARR_SIZE] = kani::any();
+        let original = arr;
+        // Cover both even and odd lengths, including the middle element of the latter.
+        let len = kani::any_where(|len: &usize| *len <= ARR_SIZE);
+        let v = &mut arr[..len];
+
+        v.reverse();
+        for i in 0..len {
+            assert_eq!(v[i], original[len - 1 - i]);
+        }
+
+        v.reverse();
+        assert_eq!(v, &original[..len]);
+    }
+
+    // pub fn is_sorted_by<'a, F>(&'a self, mut compare: F) -> bool
+    //
+    // The sort harnesses use `is_sorted_by` as their oracle, so it is checked
+    // here against the pairwise definition for an arbitrary relation.
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_is_sorted_by() {
+        const ARR_SIZE: usize = 4;
+        const DOMAIN: u8 = 3;
+        // An arbitrary relation over a small domain of elements.
+        let relation: [[bool; DOMAIN as usize]; DOMAIN as usize] = kani::any();
+        let compare = |a: &u8, b: &u8| relation[*a as usize][*b as usize];
+
+        let arr: [u8; ARR_SIZE] = kani::any();
+        kani::assume(arr.iter().all(|x| *x < DOMAIN));
+        let v = kani::slice::any_slice_of_array(&arr);
+
+        let expected = (1..v.len()).all(|i| compare(&v[i - 1], &v[i]));
+        assert_eq!(v.is_sorted_by(compare), expected);
+    }
+
+    // pub const fn split_first(&self) -> Option<(&T, &[T])>
+    // pub const fn split_last(&self) -> Option<(&T, &[T])>
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_split_first_last() {
+        const ARR_SIZE: usize = 3;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        let v = kani::slice::any_slice_of_array(&arr);
+
+        match v.split_first() {
+            None => assert!(v.is_empty()),
+            Some((first, rest)) => {
+                assert!(ptr::eq(first, &v[0]));
+                assert!(ptr::eq(rest, &v[1..]));
+                // A single element leaves an empty remainder.
+                assert_eq!(rest.is_empty(), v.len() == 1);
+            }
+        }
+
+        match v.split_last() {
+            None => assert!(v.is_empty()),
+            Some((last, rest)) => {
+                assert!(ptr::eq(last, &v[v.len() - 1]));
+                assert!(ptr::eq(rest, &v[..v.len() - 1]));
+                assert_eq!(rest.is_empty(), v.len() == 1);
+            }
+        }
+    }
+
+    // pub const fn first_chunk<const N: usize>(&self) -> Option<&[T; N]>
+    // pub const fn last_chunk<const N: usize>(&self) -> Option<&[T; N]>
+    macro_rules! check_first_last_chunk {
+        ($harness_name:ident, $chunk_len:expr) => {
+            #[kani::proof]
+            fn $harness_name() {
+                const ARR_SIZE: usize = 3;
+                const N: usize = $chunk_len;
+                let arr: [u8; ARR_SIZE] = kani::any();
+                let v = kani::slice::any_slice_of_array(&arr);
+
+                match v.first_chunk::<N>() {
+                    None => assert!(v.len() < N),
+                    Some(chunk) => {
+                        assert!(v.len() >= N);
+                        assert!(ptr::eq(chunk.as_slice(), &v[..N]));
+                    }
+                }
+
+                match v.last_chunk::<N>() {
+                    None => assert!(v.len() < N),
+                    Some(chunk) => {
+                        assert!(v.len() >= N);
+                        assert!(ptr::eq(chunk.as_slice(), &v[v.len() - N..]));
+                    }
+                }
+            }
+        };
+    }
+
+    // `N == 0` always succeeds, `N == ARR_SIZE` only on an exact fit.
+    check_first_last_chunk!(check_first_last_chunk_0, 0);
+    check_first_last_chunk!(check_first_last_chunk_2, 2);
+    check_first_last_chunk!(check_first_last_chunk_3, 3);
+
+    // pub fn copy_from_slice(&mut self, src: &[T])
+    #[kani::proof_for_contract(<[u8]>::copy_from_slice)]
+    #[kani::unwind(5)]
+    fn check_copy_from_slice() {
+        const ARR_SIZE: usize = 4;
+        let mut dst: [u8; ARR_SIZE] = kani::any();
+        let src: [u8; ARR_SIZE] = kani::any();
+        let dst = kani::slice::any_slice_of_array_mut(&mut dst);
+        let src = kani::slice::any_slice_of_array(&src);
+        dst.copy_from_slice(src);
+        assert_eq!(dst, src);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_copy_from_slice_len_mismatch() {
+        const ARR_SIZE: usize = 4;
+        let mut dst: [u8; ARR_SIZE] = kani::any();
+        let src: [u8; ARR_SIZE] = kani::any();
+        let dst = kani::slice::any_slice_of_array_mut(&mut dst);
+        let src = kani::slice::any_slice_of_array(&src);
+        kani::assume(dst.len() != src.len());
+        dst.copy_from_slice(src);
+    }
+
+    // pub fn clone_from_slice(&mut self, src: &[T]) where T: Clone
+    //
+    // A non-`Copy` element type, so that `clone_from_slice` cannot take the
+    // `copy_from_slice` shortcut.
+    #[derive(Clone, PartialEq, Debug)]
+    struct NotCopy(u8);
+
+    #[kani::proof_for_contract(<[NotCopy]>::clone_from_slice)]
+    #[kani::unwind(5)]
+    fn check_clone_from_slice() {
+        const ARR_SIZE: usize = 4;
+        let mut dst: [NotCopy; ARR_SIZE] = crate::array::from_fn(|_| NotCopy(kani::any()));
+        let src: [NotCopy; ARR_SIZE] = crate::array::from_fn(|_| NotCopy(kani::any()));
+        let dst = kani::slice::any_slice_of_array_mut(&mut dst);
+        let src = kani::slice::any_slice_of_array(&src);
+        dst.clone_from_slice(src);
+        assert_eq!(dst, src);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    #[kani::unwind(5)]
+    fn check_clone_from_slice_len_mismatch() {
+        const ARR_SIZE: usize = 4;
+        let mut dst: [NotCopy; ARR_SIZE] = crate::array::from_fn(|_| NotCopy(kani::any()));
+        let src: [NotCopy; ARR_SIZE] = crate::array::from_fn(|_| NotCopy(kani::any()));
+        let dst = kani::slice::any_slice_of_array_mut(&mut dst);
+        let src = kani::slice::any_slice_of_array(&src);
+        kani::assume(dst.len() != src.len());
+        dst.clone_from_slice(src);
+    }
+
+    // pub fn contains(&self, x: &T) -> bool where T: PartialEq
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_contains_position() {
+        const ARR_SIZE: usize = 4;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        let v = kani::slice::any_slice_of_array(&arr);
+        let x: u8 = kani::any();
+
+        let position = v.iter().position(|e| *e == x);
+        assert_eq!(v.contains(&x), position.is_some());
+        match position {
+            Some(i) => {
+                assert_eq!(v[i], x);
+                assert!(!v[..i].iter().any(|e| *e == x));
+            }
+            None => assert!(v.iter().all(|e| *e != x)),
+        }
+    }
+
+    // pub fn chunks(&self, chunk_size: usize) -> Chunks<'_, T>
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_chunks() {
+        const ARR_SIZE: usize = 5;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        let v = kani::slice::any_slice_of_array(&arr);
+        let n: usize = kani::any_where(|n: &usize| *n >= 1 && *n <= ARR_SIZE + 1);
+
+        let count = v.chunks(n).count();
+        assert_eq!(count, v.len().div_ceil(n));
+
+        // The chunks tile `v` exactly; only the last one may come up short.
+        let mut offset = 0;
+        for (i, chunk) in v.chunks(n).enumerate() {
+            if i + 1 < count {
+                assert_eq!(chunk.len(), n);
+            } else {
+                assert!(chunk.len() >= 1 && chunk.len() <= n);
+                assert_eq!(chunk.len() == n, v.len() % n == 0);
+            }
+            assert!(ptr::eq(chunk, &v[offset..offset + chunk.len()]));
+            offset += chunk.len();
+        }
+        assert_eq!(offset, v.len());
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_chunks_zero() {
+        const ARR_SIZE: usize = 4;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        let v = kani::slice::any_slice_of_array(&arr);
+        let _ = v.chunks(0);
+    }
+
+    // pub fn windows(&self, size: usize) -> Windows<'_, T>
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_windows() {
+        const ARR_SIZE: usize = 4;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        let v = kani::slice::any_slice_of_array(&arr);
+        // Allow `n > len`, which yields no windows at all.
+        let n: usize = kani::any_where(|n: &usize| *n >= 1 && *n <= ARR_SIZE + 1);
+
+        let count = v.windows(n).count();
+        if v.len() >= n {
+            assert_eq!(count, v.len() - n + 1);
+        } else {
+            assert_eq!(count, 0);
+        }
+
+        for (i, window) in v.windows(n).enumerate() {
+            assert!(ptr::eq(window, &v[i..i + n]));
+        }
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_windows_zero() {
+        const ARR_SIZE: usize = 4;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        let v = kani::slice::any_slice_of_array(&arr);
+        let _ = v.windows(0);
+    }
+
+    // pub fn iter(&self) -> Iter<'_, T>, driven from the back via `rev`
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_iter_rev() {
+        const ARR_SIZE: usize = 4;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        // Includes the empty and single-element slices.
+        let v = kani::slice::any_slice_of_array(&arr);
+        let len = v.len();
+
+        let mut count = 0;
+        for (i, x) in v.iter().rev().enumerate() {
+            assert!(ptr::eq(x, &v[len - 1 - i]));
+            count += 1;
+        }
+        assert_eq!(count, len);
+
+        let mut reversed: [u8; ARR_SIZE] = arr;
+        reversed[..len].copy_from_slice(v);
+        reversed[..len].reverse();
+        assert!(v.iter().rev().eq(reversed[..len].iter()));
+    }
+
+    // fn step_by(self, step: usize) -> StepBy<Self>, over a slice iterator
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_iter_step_by() {
+        const ARR_SIZE: usize = 4;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        let v = kani::slice::any_slice_of_array(&arr);
+        // Allow `n > len`, where only the first element is yielded.
+        let n: usize = kani::any_where(|n: &usize| *n >= 1 && *n <= ARR_SIZE + 1);
+
+        let mut count = 0;
+        for (i, x) in v.iter().step_by(n).enumerate() {
+            assert!(ptr::eq(x, &v[i * n]));
+            count += 1;
+        }
+        assert_eq!(count, v.len().div_ceil(n));
+        assert_eq!(v.iter().step_by(n).next(), v.first());
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_iter_step_by_zero() {
+        const ARR_SIZE: usize = 4;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        let v = kani::slice::any_slice_of_array(&arr);
+        let _ = v.iter().step_by(0);
+    }
+
+    // pub const fn split_at(&self, mid: usize) -> (&[T], &[T])
+    #[kani::proof]
+    fn check_split_at() {
+        const ARR_SIZE: usize = 4;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        let v = kani::slice::any_slice_of_array(&arr);
+        let mid = kani::any_where(|mid: &usize| *mid <= v.len());
+
+        let (left, right) = v.split_at(mid);
+        assert_eq!(left.len(), mid);
+        assert_eq!(right.len(), v.len() - mid);
+        // The halves are adjacent views into `v`, so together they reconstruct it.
+        assert!(ptr::eq(left.as_ptr(), v.as_ptr()));
+        assert!(ptr::eq(right.as_ptr(), v.as_ptr().wrapping_add(mid)));
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_split_at_out_of_bounds() {
+        const ARR_SIZE: usize = 4;
+        let arr: [u8; ARR_SIZE] = kani::any();
+        let v = kani::slice::any_slice_of_array(&arr);
+        let mid = kani::any_where(|mid: &usize| *mid > v.len());
+        let _ = v.split_at(mid);
+    }
+
+    // pub const fn swap(&mut self, a: usize, b: usize)
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_swap() {
+        const ARR_SIZE: usize = 4;
+        let mut arr: [u8; ARR_SIZE] = kani::any();
+        let original = arr;
+        let a = kani::any_where(|a: &usize| *a < ARR_SIZE);
+        let b = kani::any_where(|b: &usize| *b < ARR_SIZE);
+
+        arr.swap(a, b);
+        assert_eq!(arr[a], original[b]);
+        assert_eq!(arr[b], original[a]);
+        for i in 0..ARR_SIZE {
+            if i != a && i != b {
+                assert_eq!(arr[i], original[i]);
+            }
+        }
+
+        // The unchecked variant agrees whenever the bounds check passes.
+        let mut unchecked = original;
+        unsafe { unchecked.swap_unchecked(a, b) };
+        assert_eq!(unchecked, arr);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_swap_out_of_bounds() {
+        const ARR_SIZE: usize = 4;
+        let mut arr: [u8; ARR_SIZE] = kani::any();
+        let a: usize = kani::any();
+        let b: usize = kani::any();
+        kani::assume(a >= ARR_SIZE || b >= ARR_SIZE);
+        arr.swap(a, b);
+    }
+}
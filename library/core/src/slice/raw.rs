@@ -1,5 +1,9 @@
 //! Free functions to create `&[T]` and `&mut [T]`.
 
+use safety::{ensures, requires};
+
+#[cfg(kani)]
+use crate::kani;
 use crate::ops::Range;
 use crate::{array, ptr, ub_checks};
 
@@ -174,6 +178,12 @@ pub const unsafe fn from_raw_parts<'a, T>(data: *const T, len: usize) -> &'a [T]
 #[rustc_const_stable(feature = "const_slice_from_raw_parts_mut", since = "1.83.0")]
 #[must_use]
 #[rustc_diagnostic_item = "slice_from_raw_parts_mut"]
+#[requires(!data.is_null() && data.is_aligned())]
+#[requires(ub_checks::is_valid_allocation_size(size_of::<T>(), len))]
+// For `len == 0` these hold for any aligned non-null pointer, allocated or not.
+#[requires(ub_checks::can_dereference(ptr::slice_from_raw_parts(data, len)))]
+#[requires(ub_checks::can_write(ptr::slice_from_raw_parts_mut(data, len)))]
+#[ensures(|result| result.len() == len && result.as_ptr() == data.cast_const())]
 pub const unsafe fn from_raw_parts_mut<'a, T>(data: *mut T, len: usize) -> &'a mut [T] {
     // SAFETY: the caller must uphold the safety contract for `from_raw_parts_mut`.
     unsafe {
@@ -344,3 +354,34 @@ pub const unsafe fn from_mut_ptr_range<'a, T>(range: Range<*mut T>) -> &'a mut [
     // SAFETY: the caller must uphold the safety contract for `from_mut_ptr_range`.
     unsafe { from_raw_parts_mut(range.start, range.end.sub_ptr(range.start)) }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::ptr::NonNull;
+
+    // pub const unsafe fn from_raw_parts_mut<'a, T>(data: *mut T, len: usize) -> &'a mut [T]
+    #[kani::proof_for_contract(from_raw_parts_mut)]
+    #[kani::unwind(5)]
+    fn check_from_raw_parts_mut() {
+        const ARR_SIZE: usize = 4;
+        let mut arr: [u32; ARR_SIZE] = kani::any();
+        let len = kani::any_where(|len: &usize| *len <= ARR_SIZE);
+        let new_vals: [u32; ARR_SIZE] = kani::any();
+
+        let slice = unsafe { from_raw_parts_mut(arr.as_mut_ptr(), len) };
+        slice.copy_from_slice(&new_vals[..len]);
+
+        // Writes through the slice land in the buffer it was built from.
+        assert_eq!(arr[..len], new_vals[..len]);
+    }
+
+    // An empty slice needs no allocation, only an aligned non-null pointer.
+    #[kani::proof_for_contract(from_raw_parts_mut)]
+    fn check_from_raw_parts_mut_empty_dangling() {
+        let data = NonNull::<u32>::dangling().as_ptr();
+        let slice = unsafe { from_raw_parts_mut(data, 0) };
+        assert!(slice.is_empty());
+    }
+}
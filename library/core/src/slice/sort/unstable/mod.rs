@@ -82,3 +82,142 @@ where
     let limit = 2 * (len | 1).ilog2();
     crate::slice::sort::unstable::quicksort::quicksort(v, None, limit, is_less);
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    #[cfg(not(any(feature = "optimize_for_size", target_pointer_width = "16")))]
+    use crate::cell::Cell;
+    use crate::kani;
+    use crate::mem::MaybeUninit;
+    use crate::verify_support::{any_slice_within, is_permutation};
+
+    // A slice that is one strictly descending run takes the early return in
+    // `ipnsort`, which reverses it in place instead of partitioning. `sort`
+    // only reaches `ipnsort` above the insertion sort threshold, so call it
+    // directly to keep the input small.
+    #[cfg(not(any(feature = "optimize_for_size", target_pointer_width = "16")))]
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_ipnsort_descending_run() {
+        const MAX_LEN: usize = 5;
+        let mut storage = [const { MaybeUninit::uninit() }; MAX_LEN];
+        let v: &mut [u8] = any_slice_within(&mut storage);
+        let len = v.len();
+        // Shorter slices return before looking for a run.
+        kani::assume(len >= 2);
+        kani::assume(v.windows(2).all(|w| w[1] < w[0]));
+        let mut original = [0u8; MAX_LEN];
+        original[..len].copy_from_slice(v);
+
+        assert_eq!(find_existing_run(v, &mut |a: &u8, b: &u8| a < b), (len, true));
+        ipnsort(v, &mut |a: &u8, b: &u8| a < b);
+
+        assert!(v.is_sorted());
+        assert!(is_permutation(v, &original[..len]));
+        for i in 0..len {
+            assert_eq!(v[i], original[len - 1 - i]);
+        }
+    }
+
+    // Not `Freeze`, so the small sort threshold drops to its smallest value
+    // (16) and 21 elements are partitioned once before being small-sorted.
+    // A `u8` key would go straight into the 32-element sorting network.
+    #[cfg(not(any(feature = "optimize_for_size", target_pointer_width = "16")))]
+    struct Key(Cell<u8>);
+
+    // Sorts `LEN` keys drawn from a domain of four values and returns the
+    // values they started with. Only the relative order of elements matters,
+    // so four values still give duplicates and runs while keeping the
+    // symbolic state small.
+    #[cfg(not(any(feature = "optimize_for_size", target_pointer_width = "16")))]
+    fn check_sort_keys<const LEN: usize>() -> [u8; LEN] {
+        let mut v: [Key; LEN] = crate::array::from_fn(|_| Key(Cell::new(kani::any())));
+        let original: [u8; LEN] = crate::array::from_fn(|i| v[i].0.get());
+        kani::assume(original.iter().all(|x| *x < 4));
+
+        sort(&mut v, &mut |a: &Key, b: &Key| a.0.get() < b.0.get());
+
+        let sorted: [u8; LEN] = crate::array::from_fn(|i| v[i].0.get());
+        assert!(sorted.is_sorted());
+        assert!(is_permutation(&sorted, &original));
+        original
+    }
+
+    // `MAX_LEN_ALWAYS_INSERTION_SORT`: 20 elements only go through
+    // `insertion_sort_shift_left`.
+    #[cfg(not(any(feature = "optimize_for_size", target_pointer_width = "16")))]
+    #[kani::proof]
+    #[kani::unwind(22)]
+    fn check_sort_len_20() {
+        check_sort_keys::<20>();
+    }
+
+    // One element more goes through `ipnsort`, and unless the input is a
+    // single run, on into `quicksort`, which has to partition it.
+    #[cfg(not(any(feature = "optimize_for_size", target_pointer_width = "16")))]
+    #[kani::proof]
+    #[kani::unwind(22)]
+    fn check_sort_len_21() {
+        let original = check_sort_keys::<21>();
+        kani::cover!(find_existing_run(&original, &mut |a: &u8, b: &u8| a < b).0 < 21);
+    }
+
+    #[derive(PartialEq, Eq, Debug, Clone, Copy)]
+    struct Zst;
+
+    // Slices of zero-sized types are sorted by definition, so `sort` must
+    // return without comparing (or moving) anything, whatever the length.
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_sort_zst() {
+        const LEN: usize = 8;
+        let mut arr = [Zst; LEN];
+        let len = kani::any_where(|len: &usize| *len <= LEN);
+        let v = &mut arr[..len];
+        let ptr = v.as_ptr();
+
+        sort(v, &mut |_: &Zst, _: &Zst| -> bool {
+            panic!("ZST slices must not be compared");
+        });
+
+        assert_eq!(v.len(), len);
+        assert_eq!(v.as_ptr(), ptr);
+        assert!(v.iter().all(|x| *x == Zst));
+    }
+
+    // A reversed comparator sorts in descending order.
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_sort_reverse_comparator() {
+        const MAX_LEN: usize = 5;
+        let mut storage = [const { MaybeUninit::uninit() }; MAX_LEN];
+        let v: &mut [u8] = any_slice_within(&mut storage);
+        let mut original = [0u8; MAX_LEN];
+        original[..v.len()].copy_from_slice(v);
+
+        sort(v, &mut |a: &u8, b: &u8| a > b);
+
+        assert!(v.is_sorted_by(|a, b| a >= b));
+        assert!(is_permutation(v, &original[..v.len()]));
+    }
+
+    // A comparator that only looks at part of the value treats distinct
+    // elements as equal. Their relative order is unspecified, but the result
+    // must still be sorted by the compared part and be a permutation.
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_sort_coarse_comparator() {
+        const MAX_LEN: usize = 5;
+        let mut storage = [const { MaybeUninit::uninit() }; MAX_LEN];
+        let v: &mut [u8] = any_slice_within(&mut storage);
+        let mut original = [0u8; MAX_LEN];
+        original[..v.len()].copy_from_slice(v);
+
+        sort(v, &mut |a: &u8, b: &u8| a / 4 < b / 4);
+
+        assert!(v.is_sorted_by_key(|x| x / 4));
+        assert!(is_permutation(v, &original[..v.len()]));
+    }
+}
@@ -389,3 +389,68 @@ impl<T> Drop for GapGuardRaw<T> {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+#[cfg(not(feature = "optimize_for_size"))]
+mod verify {
+    use super::*;
+    use crate::cell::Cell;
+    use crate::kani;
+    use crate::verify_support::is_permutation;
+
+    // Not `Freeze`, so the small sort threshold is the smallest one (16), and
+    // the slices below only need one element more to reach the partitioning.
+    struct Key(Cell<u8>);
+
+    const LEN: usize = 17;
+
+    fn any_keys() -> [Key; LEN] {
+        crate::array::from_fn(|_| Key(Cell::new(kani::any())))
+    }
+
+    fn values(v: &[Key; LEN]) -> [u8; LEN] {
+        crate::array::from_fn(|i| v[i].0.get())
+    }
+
+    // Once the recursion limit is exhausted, `quicksort` must hand the slice
+    // over to `heapsort` instead of partitioning it. With a limit of zero that
+    // happens right away, so it must compare exactly like `heapsort` does.
+    #[kani::proof]
+    #[kani::unwind(18)]
+    fn check_quicksort_limit_zero_falls_back_to_heapsort() {
+        let mut v = any_keys();
+        let mut w: [Key; LEN] = crate::array::from_fn(|i| Key(Cell::new(v[i].0.get())));
+        let original = values(&v);
+
+        let mut quicksort_cmps = 0;
+        quicksort(&mut v, None, 0, &mut |a: &Key, b: &Key| {
+            quicksort_cmps += 1;
+            a.0.get() < b.0.get()
+        });
+        let mut heapsort_cmps = 0;
+        heapsort::heapsort(&mut w, &mut |a: &Key, b: &Key| {
+            heapsort_cmps += 1;
+            a.0.get() < b.0.get()
+        });
+
+        assert_eq!(quicksort_cmps, heapsort_cmps);
+        assert_eq!(values(&v), values(&w));
+        assert!(values(&v).is_sorted());
+        assert!(is_permutation(&values(&v), &original));
+    }
+
+    // Whenever the fallback kicks in, the result is still sorted.
+    #[kani::proof]
+    #[kani::unwind(18)]
+    fn check_quicksort_small_limit() {
+        let mut v = any_keys();
+        let original = values(&v);
+        let limit = kani::any_where(|limit: &u32| *limit <= 2);
+
+        quicksort(&mut v, None, limit, &mut |a: &Key, b: &Key| a.0.get() < b.0.get());
+
+        assert!(values(&v).is_sorted());
+        assert!(is_permutation(&values(&v), &original));
+    }
+}
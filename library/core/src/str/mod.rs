@@ -13,8 +13,12 @@ mod iter;
 mod traits;
 mod validations;
 
+use safety::{ensures, requires};
+
 use self::pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher, Searcher};
 use crate::char::{self, EscapeDebugExtArgs};
+#[cfg(kani)]
+use crate::kani;
 use crate::ops::Range;
 use crate::slice::{self, SliceIndex};
 use crate::{ascii, mem};
@@ -643,6 +647,8 @@ impl str {
     #[must_use]
     #[stable(feature = "str_split_at", since = "1.4.0")]
     #[rustc_const_unstable(feature = "const_str_split_at", issue = "131518")]
+    #[requires(self.is_char_boundary(mid))]
+    #[ensures(|result: &(&str, &str)| result.0.len() == mid && result.1.len() == self.len() - mid)]
     pub const fn split_at(&self, mid: usize) -> (&str, &str) {
         match self.split_at_checked(mid) {
             None => slice_error_fail(self, 0, mid),
@@ -2869,3 +2875,79 @@ impl_fn_for_zst! {
 // This is required to make `impl From<&str> for Box<dyn Error>` and `impl<E> From<E> for Box<dyn Error>` not overlap.
 #[stable(feature = "error_in_core_neg_impl", since = "1.65.0")]
 impl !crate::error::Error for &str {}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::verify_support::any_str_within;
+
+    // pub const fn split_at(&self, mid: usize) -> (&str, &str)
+    #[kani::proof_for_contract(str::split_at)]
+    #[kani::unwind(9)]
+    fn check_split_at() {
+        let mut storage = [0u8; 8];
+        let s = any_str_within(&mut storage);
+        let mid: usize = kani::any();
+
+        let (first, last) = s.split_at(mid);
+
+        // Both halves are valid UTF-8 on their own and put back together
+        // they are the original string.
+        assert!(from_utf8(first.as_bytes()).is_ok());
+        assert!(from_utf8(last.as_bytes()).is_ok());
+        assert_eq!(first.as_ptr(), s.as_ptr());
+        assert_eq!(last.as_ptr(), s.as_ptr().wrapping_add(mid));
+    }
+
+    // A `mid` inside a multi-byte character is rejected.
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_split_at_inside_char() {
+        let c: char = kani::any_where(|c: &char| c.len_utf8() > 1);
+        let mut buf = [0u8; 4];
+        let s = c.encode_utf8(&mut buf);
+        let mid = kani::any_where(|mid: &usize| *mid > 0 && *mid < s.len());
+        let _ = s.split_at(mid);
+    }
+
+    // pub fn char_indices(&self) -> CharIndices<'_>
+    #[kani::proof]
+    #[kani::unwind(13)]
+    fn check_char_indices() {
+        let mut storage = [0u8; 12];
+        let s = any_str_within(&mut storage);
+
+        let mut reencoded = [0u8; 12];
+        let mut len = 0;
+        let mut prev: Option<usize> = None;
+        for (offset, c) in s.char_indices() {
+            assert!(s.is_char_boundary(offset));
+            // Each offset is exactly past the previous character, which may
+            // be several bytes long.
+            assert_eq!(offset, len);
+            if let Some(prev) = prev {
+                assert!(offset > prev);
+            }
+            prev = Some(offset);
+            len += c.encode_utf8(&mut reencoded[len..]).len();
+        }
+        assert_eq!(&reencoded[..len], s.as_bytes());
+    }
+
+    // pub fn bytes(&self) -> Bytes<'_>
+    // pub fn chars(&self) -> Chars<'_>
+    #[kani::proof]
+    #[kani::unwind(13)]
+    fn check_bytes_chars_count() {
+        let mut storage = [0u8; 12];
+        let s = any_str_within(&mut storage);
+
+        let bytes = s.bytes().count();
+        let chars = s.chars().count();
+        assert_eq!(bytes, s.len());
+        assert!(bytes >= chars);
+        // Every non-ASCII char takes more than one byte.
+        assert_eq!(bytes == chars, s.is_ascii());
+    }
+}
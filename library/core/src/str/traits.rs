@@ -1,9 +1,15 @@
 //! Trait implementations for `str`.
 
+use safety::{ensures, requires};
+
 use super::ParseBoolError;
 use crate::cmp::Ordering;
 use crate::intrinsics::unchecked_sub;
+#[cfg(kani)]
+use crate::kani;
 use crate::slice::SliceIndex;
+#[cfg(kani)]
+use crate::ub_checks;
 use crate::ub_checks::assert_unsafe_precondition;
 use crate::{ops, ptr, range};
 
@@ -186,6 +192,11 @@ unsafe impl SliceIndex<str> for ops::Range<usize> {
         }
     }
     #[inline]
+    #[requires(self.start <= self.end && self.end <= (slice as *const [u8]).len())]
+    #[requires(ub_checks::can_dereference(slice))]
+    // SAFETY: `slice` was just required to be dereferenceable.
+    #[requires(unsafe { (*slice).is_char_boundary(self.start) && (*slice).is_char_boundary(self.end) })]
+    #[ensures(|result| unsafe { (**result).as_bytes() == &(*slice).as_bytes()[self.start..self.end] })]
     unsafe fn get_unchecked(self, slice: *const str) -> *const Self::Output {
         let slice = slice as *const [u8];
 
@@ -288,6 +299,11 @@ unsafe impl SliceIndex<str> for range::Range<usize> {
         }
     }
     #[inline]
+    #[requires(self.start <= self.end && self.end <= (slice as *const [u8]).len())]
+    #[requires(ub_checks::can_dereference(slice))]
+    // SAFETY: `slice` was just required to be dereferenceable.
+    #[requires(unsafe { (*slice).is_char_boundary(self.start) && (*slice).is_char_boundary(self.end) })]
+    #[ensures(|result| unsafe { (**result).as_bytes() == &(*slice).as_bytes()[self.start..self.end] })]
     unsafe fn get_unchecked(self, slice: *const str) -> *const Self::Output {
         let slice = slice as *const [u8];
 
@@ -858,3 +874,34 @@ impl FromStr for bool {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::verify_support::any_str_within;
+
+    // unsafe fn get_unchecked(self, slice: *const str) -> *const str
+    #[kani::proof_for_contract(<ops::Range<usize> as SliceIndex<str>>::get_unchecked)]
+    #[kani::unwind(5)]
+    fn check_range_get_unchecked() {
+        let mut buf = [0u8; 8];
+        let s = any_str_within(&mut buf);
+        let range = kani::any::<usize>()..kani::any::<usize>();
+        let _ = unsafe { range.get_unchecked(s as *const str) };
+    }
+
+    // pub unsafe fn get_unchecked<I: SliceIndex<str>>(&self, i: I) -> &I::Output
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_str_get_unchecked_range() {
+        let mut buf = [0u8; 8];
+        let s = any_str_within(&mut buf);
+        let start: usize = kani::any();
+        let end: usize = kani::any();
+        // A range that lands inside a multi-byte char is not accepted.
+        kani::assume(start <= end && s.is_char_boundary(start) && s.is_char_boundary(end));
+        let sub = unsafe { s.get_unchecked(start..end) };
+        assert_eq!(sub, &s[start..end]);
+    }
+}
@@ -3809,3 +3809,38 @@ impl<T> fmt::Pointer for AtomicPtr<T> {
 pub fn spin_loop_hint() {
     spin_loop()
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::kani;
+
+    /// Picks any of the memory orderings accepted by read-modify-write operations.
+    fn any_rmw_ordering() -> Ordering {
+        match kani::any::<u8>() % 5 {
+            0 => Relaxed,
+            1 => Release,
+            2 => Acquire,
+            3 => AcqRel,
+            _ => SeqCst,
+        }
+    }
+
+    // pub fn fetch_add(&self, val: u32, order: Ordering) -> u32
+    //
+    // `fetch_add` wraps around on overflow instead of panicking, and always
+    // returns the value stored before the addition.
+    #[cfg(target_has_atomic = "32")]
+    #[kani::proof]
+    fn check_atomic_u32_fetch_add_wraps() {
+        let old: u32 = kani::any();
+        let val: u32 = kani::any();
+        let atomic = AtomicU32::new(old);
+
+        let prev = atomic.fetch_add(val, any_rmw_ordering());
+
+        assert_eq!(prev, old);
+        assert_eq!(atomic.load(SeqCst), old.wrapping_add(val));
+    }
+}
@@ -171,6 +171,21 @@ pub(crate) const fn maybe_is_nonoverlapping(
     )
 }
 
+/// Checks whether the regions of memory starting at `src` and `dst` of size
+/// `count * size` do *not* overlap.
+///
+/// Unlike `maybe_is_nonoverlapping`, this is meant to be used in safety contracts:
+/// it returns `false` instead of panicking if `count * size` overflows a `usize`.
+/// Empty regions, i.e., if `count` is zero or the pointee is a ZST, never overlap.
+pub fn is_nonoverlapping(src: *const (), dst: *const (), size: usize, count: usize) -> bool {
+    let Some(size) = size.checked_mul(count) else {
+        return false;
+    };
+    // If the absolute distance between the ptrs is at least as big as the size of the buffer,
+    // they do not overlap.
+    src.addr().abs_diff(dst.addr()) >= size
+}
+
 pub use predicates::*;
 
 /// Provide a few predicates to be used in safety contracts.
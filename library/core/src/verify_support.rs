@@ -0,0 +1,96 @@
+//! Helpers shared by the verification harnesses across the standard library.
+//!
+//! These complement the API provided by the `kani` module and are only
+//! available when building with Kani.
+
+use crate::cell::Cell;
+use crate::kani;
+use crate::mem::MaybeUninit;
+
+/// Creates an array of length `N` where every element is an arbitrary value.
+///
+/// This is shorthand for `kani::Arbitrary::any_array()` that lets the element
+/// type and length be spelled out at the call site:
+///
+/// ```ignore
+/// let arr = any_array::<u32, 4>();
+/// ```
+pub fn any_array<T: kani::Arbitrary, const N: usize>() -> [T; N] {
+    T::any_array()
+}
+
+/// Returns a slice of arbitrary length in `0..=N` with arbitrary contents.
+///
+/// The slice borrows from `storage`, which the caller provides so that the
+/// result can outlive this call. Using a symbolic length lets a single harness
+/// cover every slice length up to `N`.
+///
+/// ```ignore
+/// let mut storage = [const { MaybeUninit::uninit() }; 8];
+/// let v = any_slice_within::<u32, 8>(&mut storage);
+/// ```
+pub fn any_slice_within<T: kani::Arbitrary, const N: usize>(
+    storage: &mut [MaybeUninit<T>; N],
+) -> &mut [T] {
+    // Initialize the whole buffer so that the loop bound is concrete.
+    for elem in storage.iter_mut() {
+        elem.write(kani::any());
+    }
+    let len = kani::any_where(|len: &usize| *len <= N);
+    // SAFETY: every element of `storage` was initialized above.
+    unsafe { MaybeUninit::slice_assume_init_mut(&mut storage[..len]) }
+}
+
+/// Checks whether `a` and `b` contain the same elements with the same
+/// multiplicities, i.e., whether one is a permutation of the other.
+///
+/// This compares occurrence counts instead of sorting or allocating, which
+/// keeps the generated formula small for the short slices used in harnesses.
+pub fn is_permutation<T: Eq>(a: &[T], b: &[T]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let count = |s: &[T], x: &T| s.iter().filter(|y| *y == x).count();
+    // Since both slices have the same length, matching the counts of every
+    // element of `a` leaves no room for `b` to contain anything else.
+    a.iter().all(|x| count(a, x) == count(b, x))
+}
+
+/// Returns a valid UTF-8 string made of up to `N / 4` arbitrary `char`s.
+///
+/// The string is encoded into `storage`. Building it from `char`s rather than
+/// constraining arbitrary bytes with `from_utf8` keeps the harness cheap while
+/// still covering every encoded length from one to four bytes.
+pub fn any_str_within<const N: usize>(storage: &mut [u8; N]) -> &str {
+    let mut len = 0;
+    for _ in 0..N / 4 {
+        if kani::any() {
+            let c: char = kani::any();
+            len += c.encode_utf8(&mut storage[len..]).len();
+        }
+    }
+    // SAFETY: `storage[..len]` is a concatenation of UTF-8 encoded `char`s.
+    unsafe { crate::str::from_utf8_unchecked(&storage[..len]) }
+}
+
+/// A value that counts its drops in a shared `Cell`.
+///
+/// Harnesses create several counters sharing one `drops` cell and check it
+/// to tell whether an operation moved, leaked or dropped a value. `value`
+/// tells instances apart.
+pub struct DropCounter<'a, T> {
+    pub value: T,
+    pub drops: &'a Cell<usize>,
+}
+
+impl<'a, T> DropCounter<'a, T> {
+    pub fn new(value: T, drops: &'a Cell<usize>) -> Self {
+        DropCounter { value, drops }
+    }
+}
+
+impl<T> Drop for DropCounter<'_, T> {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
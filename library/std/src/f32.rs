@@ -1150,3 +1150,34 @@ impl f32 {
         (x, signgamp)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::kani;
+
+    // pub fn mul_add(self, a: f32, b: f32) -> f32
+    //
+    // For small integer operands, `self * a + b` is exactly representable, so
+    // the fused and the separately rounded computations must agree.
+    #[kani::proof]
+    pub fn check_f32_mul_add_exact() {
+        let x: i16 = kani::any_where(|x: &i16| x.unsigned_abs() <= 2048);
+        let a: i16 = kani::any_where(|a: &i16| a.unsigned_abs() <= 2048);
+        let b: i32 = kani::any_where(|b: &i32| b.unsigned_abs() <= 4194304);
+        // |x * a| and |b| are both at most 2^22, so the sum fits in
+        // the 24-bit significand.
+        let (x, a, b) = (x as f32, a as f32, b as f32);
+        assert_eq!(x.mul_add(a, b), x * a + b);
+    }
+
+    // With x = 1 + 2^-12, x * x = 1 + 2 * (x - 1) + 2^-24. Rounding the product
+    // drops the 2^-24 term, which a single rounding must keep.
+    #[kani::proof]
+    pub fn check_f32_mul_add_single_rounding() {
+        let x: f32 = 1.0 + 1.0 / 4096.0;
+        let square: f32 = 1.0 + 1.0 / 2048.0;
+        assert_eq!(x * x - square, 0.0);
+        assert_eq!(x.mul_add(x, -square), 1.0 / 16777216.0);
+    }
+}
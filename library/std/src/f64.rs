@@ -1150,3 +1150,34 @@ impl f64 {
         (x, signgamp)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::kani;
+
+    // pub fn mul_add(self, a: f64, b: f64) -> f64
+    //
+    // For small integer operands, `self * a + b` is exactly representable, so
+    // the fused and the separately rounded computations must agree.
+    #[kani::proof]
+    pub fn check_f64_mul_add_exact() {
+        let x: i32 = kani::any_where(|x: &i32| x.unsigned_abs() <= 67108864);
+        let a: i32 = kani::any_where(|a: &i32| a.unsigned_abs() <= 67108864);
+        let b: i64 = kani::any_where(|b: &i64| b.unsigned_abs() <= 4503599627370496);
+        // |x * a| and |b| are both at most 2^52, so the sum fits in
+        // the 53-bit significand.
+        let (x, a, b) = (x as f64, a as f64, b as f64);
+        assert_eq!(x.mul_add(a, b), x * a + b);
+    }
+
+    // With x = 1 + 2^-27, x * x = 1 + 2 * (x - 1) + 2^-54. Rounding the product
+    // drops the 2^-54 term, which a single rounding must keep.
+    #[kani::proof]
+    pub fn check_f64_mul_add_single_rounding() {
+        let x: f64 = 1.0 + 1.0 / 134217728.0;
+        let square: f64 = 1.0 + 1.0 / 67108864.0;
+        assert_eq!(x * x - square, 0.0);
+        assert_eq!(x.mul_add(x, -square), 1.0 / 18014398509481984.0);
+    }
+}
@@ -31,6 +31,9 @@ pub mod thread;
 pub mod thread_parking;
 pub mod time;
 
+#[cfg(test)]
+mod tests;
+
 #[cfg(target_os = "espidf")]
 pub fn init(_argc: isize, _argv: *const *const u8, _sigpipe: u8) {}
 
@@ -0,0 +1,18 @@
+use super::cvt_nz;
+use crate::io::ErrorKind;
+
+#[test]
+fn cvt_nz_zero_is_ok() {
+    assert!(cvt_nz(0).is_ok());
+}
+
+#[test]
+fn cvt_nz_keeps_the_raw_error() {
+    // The pthread functions return their error code instead of setting `errno`.
+    for code in [libc::EINVAL, libc::EBUSY, libc::EAGAIN, libc::ENOMEM, libc::EPERM] {
+        let err = cvt_nz(code).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(code));
+    }
+    assert_eq!(cvt_nz(libc::EINVAL).unwrap_err().kind(), ErrorKind::InvalidInput);
+    assert_eq!(cvt_nz(libc::EBUSY).unwrap_err().kind(), ErrorKind::ResourceBusy);
+}
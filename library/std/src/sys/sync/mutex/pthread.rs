@@ -1,8 +1,12 @@
+#[cfg(test)]
+mod tests;
+
 use crate::cell::UnsafeCell;
 use crate::io::Error;
 use crate::mem::{MaybeUninit, forget};
 use crate::sys::cvt_nz;
 use crate::sys::sync::OnceBox;
+use crate::time::Duration;
 
 struct AllocatedMutex(UnsafeCell<libc::pthread_mutex_t>);
 
@@ -43,13 +47,7 @@ impl AllocatedMutex {
         // re-lock it from the same thread, thus avoiding undefined behavior.
         unsafe {
             let mut attr = MaybeUninit::<libc::pthread_mutexattr_t>::uninit();
-            cvt_nz(libc::pthread_mutexattr_init(attr.as_mut_ptr())).unwrap();
-            let attr = PthreadMutexAttr(&mut attr);
-            cvt_nz(libc::pthread_mutexattr_settype(
-                attr.0.as_mut_ptr(),
-                libc::PTHREAD_MUTEX_NORMAL,
-            ))
-            .unwrap();
+            let attr = PthreadMutexAttr::new_normal(&mut attr);
             cvt_nz(libc::pthread_mutex_init(mutex.0.get(), attr.0.as_ptr())).unwrap();
         }
 
@@ -103,13 +101,6 @@ impl Mutex {
 
     #[inline]
     pub fn lock(&self) {
-        #[cold]
-        #[inline(never)]
-        fn fail(r: i32) -> ! {
-            let error = Error::from_raw_os_error(r);
-            panic!("failed to lock mutex: {error}");
-        }
-
         let r = unsafe { libc::pthread_mutex_lock(self.get()) };
         // As we set the mutex type to `PTHREAD_MUTEX_NORMAL` above, we expect
         // the lock call to never fail. Unfortunately however, some platforms
@@ -119,7 +110,7 @@ impl Mutex {
         // less well-behaved platforms in the future, we do it even on "good"
         // platforms like macOS. See #120147 for more context.
         if r != 0 {
-            fail(r)
+            lock_failed(r)
         }
     }
 
@@ -133,6 +124,67 @@ impl Mutex {
     pub fn try_lock(&self) -> bool {
         unsafe { libc::pthread_mutex_trylock(self.get()) == 0 }
     }
+
+    /// Attempts to acquire the lock, giving up once `timeout` has elapsed.
+    ///
+    /// Returns `true` if the lock was acquired. As the mutex is created with
+    /// type `PTHREAD_MUTEX_NORMAL`, trying to re-lock it from the thread that
+    /// already holds it simply times out.
+    // Only the unit tests call this until `std::sync::Mutex` gains a timed lock.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub unsafe fn try_lock_for(&self, timeout: Duration) -> bool {
+        unsafe { timed_lock(self, timeout) }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn lock_failed(r: i32) -> ! {
+    let error = Error::from_raw_os_error(r);
+    panic!("failed to lock mutex: {error}");
+}
+
+cfg_if::cfg_if! {
+    // Among the targets using this mutex, these are the ones known to provide
+    // `pthread_mutex_timedlock`.
+    if #[cfg(any(target_os = "illumos", target_os = "netbsd", target_os = "solaris"))] {
+        unsafe fn timed_lock(mutex: &Mutex, timeout: Duration) -> bool {
+            use crate::sys::time::{TIMESPEC_MAX, Timespec};
+
+            // `pthread_mutex_timedlock` measures the deadline against the
+            // system clock.
+            let deadline = Timespec::now(libc::CLOCK_REALTIME)
+                .checked_add_duration(&timeout)
+                .and_then(|t| t.to_timespec())
+                .unwrap_or(TIMESPEC_MAX);
+
+            let r = unsafe { libc::pthread_mutex_timedlock(mutex.get(), &deadline) };
+            match r {
+                0 => true,
+                // Platforms which always detect deadlocks (see `lock`) report a
+                // re-lock from the owning thread instead of waiting for the timeout.
+                libc::ETIMEDOUT | libc::EDEADLK => false,
+                // See `lock` for why other errors cannot be ignored.
+                r => lock_failed(r),
+            }
+        }
+    } else {
+        // Everywhere else, poll `try_lock` until the timeout expires.
+        unsafe fn timed_lock(mutex: &Mutex, timeout: Duration) -> bool {
+            use crate::time::Instant;
+
+            let start = Instant::now();
+            loop {
+                if mutex.try_lock() {
+                    return true;
+                }
+                if start.elapsed() >= timeout {
+                    return false;
+                }
+                crate::thread::yield_now();
+            }
+        }
+    }
 }
 
 impl Drop for Mutex {
@@ -153,6 +205,25 @@ impl Drop for Mutex {
 
 pub(super) struct PthreadMutexAttr<'a>(pub &'a mut MaybeUninit<libc::pthread_mutexattr_t>);
 
+impl<'a> PthreadMutexAttr<'a> {
+    /// Initializes `attr` with the mutex type set to `PTHREAD_MUTEX_NORMAL`.
+    ///
+    /// See the comment in `AllocatedMutex::new` for why the type must always
+    /// be set explicitly.
+    unsafe fn new_normal(attr: &'a mut MaybeUninit<libc::pthread_mutexattr_t>) -> Self {
+        unsafe {
+            cvt_nz(libc::pthread_mutexattr_init(attr.as_mut_ptr())).unwrap();
+            let attr = PthreadMutexAttr(attr);
+            cvt_nz(libc::pthread_mutexattr_settype(
+                attr.0.as_mut_ptr(),
+                libc::PTHREAD_MUTEX_NORMAL,
+            ))
+            .unwrap();
+            attr
+        }
+    }
+}
+
 impl Drop for PthreadMutexAttr<'_> {
     fn drop(&mut self) {
         unsafe {
@@ -0,0 +1,77 @@
+use super::{Mutex, PthreadMutexAttr};
+use crate::mem::MaybeUninit;
+use crate::time::Duration;
+
+// Not every target's `libc` bindings expose the getter, but it is part of
+// POSIX and available wherever this mutex implementation is used.
+extern "C" {
+    fn pthread_mutexattr_gettype(
+        attr: *const libc::pthread_mutexattr_t,
+        kind: *mut libc::c_int,
+    ) -> libc::c_int;
+}
+
+// Issue #33770: the mutex must never be left with the default type.
+#[test]
+fn attr_type_is_normal() {
+    let mut attr = MaybeUninit::uninit();
+    unsafe {
+        let attr = PthreadMutexAttr::new_normal(&mut attr);
+        let mut kind = -1;
+        assert_eq!(pthread_mutexattr_gettype(attr.0.as_ptr(), &mut kind), 0);
+        assert_eq!(kind, libc::PTHREAD_MUTEX_NORMAL);
+    }
+}
+
+#[test]
+fn relock_is_refused() {
+    let m = Mutex::new();
+    m.lock();
+    assert!(!m.try_lock());
+    unsafe { m.unlock() };
+    assert!(m.try_lock());
+    unsafe { m.unlock() };
+}
+
+#[test]
+fn try_lock_for_times_out_when_held() {
+    let m = Mutex::new();
+    m.lock();
+    // Re-locking a `PTHREAD_MUTEX_NORMAL` mutex must not succeed, even with a timeout.
+    assert!(!unsafe { m.try_lock_for(Duration::from_millis(10)) });
+    unsafe { m.unlock() };
+    assert!(unsafe { m.try_lock_for(Duration::from_millis(10)) });
+    unsafe { m.unlock() };
+}
+
+#[test]
+fn drop_uninitialized() {
+    let m = Mutex::new();
+    drop(m);
+}
+
+#[test]
+fn drop_destroys_unlocked() {
+    // `AllocatedMutex::drop` asserts that `pthread_mutex_destroy` succeeded,
+    // which it only does for an unlocked mutex.
+    let m = Mutex::new();
+    m.lock();
+    unsafe { m.unlock() };
+    drop(m);
+}
+
+#[test]
+fn drop_leaks_locked() {
+    // This is what happens when a `MutexGuard` is leaked.
+    let m = Mutex::new();
+    m.lock();
+    let raw = unsafe { m.get_assert_locked() };
+    drop(m);
+    // The allocation must have been leaked instead of destroyed, so the
+    // pthread mutex is still live and locked.
+    unsafe {
+        assert_eq!(libc::pthread_mutex_trylock(raw), libc::EBUSY);
+        assert_eq!(libc::pthread_mutex_unlock(raw), 0);
+        assert_eq!(libc::pthread_mutex_destroy(raw), 0);
+    }
+}
@@ -5,6 +5,9 @@
 
 #![allow(dead_code)] // Only used on some platforms.
 
+#[cfg(test)]
+mod tests;
+
 use crate::mem::replace;
 use crate::ptr::null_mut;
 use crate::sync::atomic::AtomicPtr;
@@ -0,0 +1,60 @@
+use super::OnceBox;
+use crate::cell::Cell;
+use crate::ptr;
+
+struct DropCounter<'a>(&'a Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn get_or_init_runs_once() {
+    let calls = Cell::new(0);
+    let once = OnceBox::new();
+
+    let first: *const u32 = once.get_or_init(|| {
+        calls.set(calls.get() + 1);
+        Box::new(42)
+    });
+    let second: *const u32 = once.get_or_init(|| panic!("initialized twice"));
+
+    assert_eq!(calls.get(), 1);
+    assert!(ptr::eq(first, second));
+    assert_eq!(unsafe { *once.get_unchecked() }, 42);
+}
+
+#[test]
+fn losing_the_race_drops_only_the_new_value() {
+    let drops = Cell::new(0);
+    let once = OnceBox::new();
+    let winner: *const DropCounter<'_> = once.get_or_init(|| Box::new(DropCounter(&drops)));
+
+    // Simulate another thread having won the race: the freshly created value
+    // must be dropped and the stored one kept.
+    let current: *const DropCounter<'_> = once.initialize(|| Box::new(DropCounter(&drops)));
+    assert_eq!(drops.get(), 1);
+    assert!(ptr::eq(winner, current));
+
+    drop(once);
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn take_resets() {
+    let drops = Cell::new(0);
+    let mut once = OnceBox::new();
+    assert!(once.take().is_none());
+
+    once.get_or_init(|| Box::new(DropCounter(&drops)));
+    drop(once.take().unwrap());
+    assert_eq!(drops.get(), 1);
+    assert!(once.take().is_none());
+
+    // Once taken, the box can be initialized again.
+    once.get_or_init(|| Box::new(DropCounter(&drops)));
+    drop(once);
+    assert_eq!(drops.get(), 2);
+}
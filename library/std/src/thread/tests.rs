@@ -341,6 +341,40 @@ fn test_thread_id_not_equal() {
     assert!(thread::current().id() != spawned_id);
 }
 
+#[test]
+fn test_set_current_once() {
+    thread::spawn(|| {
+        let current = thread::current();
+
+        // The handle is installed when the thread starts, so it cannot be replaced.
+        let other = thread::Thread::new_unnamed(ThreadId::new());
+        let other_id = other.id();
+        let rejected = thread::set_current(other).unwrap_err();
+        assert_eq!(rejected.id(), other_id);
+
+        let same = thread::set_current(current.clone()).unwrap_err();
+        assert_eq!(same.id(), current.id());
+
+        assert_eq!(thread::current().id(), current.id());
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn test_current_is_consistent() {
+    thread::spawn(|| {
+        let a = thread::current();
+        let b = thread::current();
+        assert_eq!(a.id(), b.id());
+        assert_eq!(a.id(), thread::current_id());
+        assert_eq!(thread::try_current().map(|t| t.id()), Some(a.id()));
+        assert_eq!(thread::current_or_unnamed().id(), a.id());
+    })
+    .join()
+    .unwrap();
+}
+
 #[test]
 fn test_scoped_threads_drop_result_before_join() {
     let actually_finished = &AtomicBool::new(false);